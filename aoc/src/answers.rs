@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::io::ErrorKind::InvalidData;
+use std::io::{BufRead, BufReader, Error, Read};
+
+// Expected answers keyed by (day, part) - the manifest is a minimal TOML
+// subset with a [dayNN] table per day and integer or string values:
+//
+//   [day01]
+//   part1 = 55816
+//   part2 = "ABC"
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(HashMap<(u32, u32), String>);
+
+fn invalid(line: usize, msg: &str) -> Error {
+    Error::new(InvalidData, format!("answers line {}: {}", line, msg))
+}
+
+impl Answers {
+    pub fn parse(input: &mut impl Read) -> std::io::Result<Self> {
+        let mut answers = HashMap::new();
+        let mut day: Option<u32> = None;
+        for (i, l) in BufReader::new(input).lines().enumerate() {
+            let l = l?;
+            let l = l.split('#').next().unwrap().trim();
+            if l.is_empty() {
+                continue;
+            }
+            if let Some(table) = l.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = match table.trim().strip_prefix("day").map(|d| d.parse::<u32>()) {
+                    Some(Ok(d @ 1..=25)) => Some(d),
+                    _ => return Err(invalid(i + 1, "expected [dayNN]")),
+                };
+                continue;
+            }
+            let day = day.ok_or_else(|| invalid(i + 1, "value outside [dayNN] table"))?;
+            let (k, v) = l
+                .split_once('=')
+                .ok_or_else(|| invalid(i + 1, "expected key = value"))?;
+            let part = match k.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(invalid(i + 1, "expected part1 or part2")),
+            };
+            let v = v.trim();
+            let v = match v.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(s) => s,
+                None if v.parse::<i64>().is_ok() => v,
+                None => return Err(invalid(i + 1, "expected integer or string")),
+            };
+            answers.insert((day, part), v.to_string());
+        }
+        Ok(Answers(answers))
+    }
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(day, part)).map(|s| s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let a = Answers::parse(
+            &mut "# Comment\n[day01]\npart1 = 123\npart2 = \"abc\" # Comment\n\n[day25]\npart1 = -5\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(a.get(1, 1), Some("123"));
+        assert_eq!(a.get(1, 2), Some("abc"));
+        assert_eq!(a.get(25, 1), Some("-5"));
+        assert_eq!(a.get(25, 2), None);
+    }
+
+    #[test]
+    fn test_answers_invalid() {
+        assert!(Answers::parse(&mut "part1 = 1\n".as_bytes()).is_err());
+        assert!(Answers::parse(&mut "[day26]\n".as_bytes()).is_err());
+        assert!(Answers::parse(&mut "[day01]\npart3 = 1\n".as_bytes()).is_err());
+        assert!(Answers::parse(&mut "[day01]\npart1 = abc\n".as_bytes()).is_err());
+        assert!(Answers::parse(&mut "[day01]\npart1\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::json::{self, Value};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+        let mut s = samples.to_vec();
+        s.sort();
+        let n = s.len();
+        let median = if n.is_multiple_of(2) {
+            (s[n / 2 - 1] + s[n / 2]) / 2
+        } else {
+            s[n / 2]
+        };
+        Stats {
+            min: s[0],
+            median,
+            p95: s[(n * 95).div_ceil(100) - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub day: u32,
+    pub stage: String,
+    pub stats: Stats,
+}
+
+// Durations are saved as (f64) seconds
+pub fn to_json(timings: &[Timing]) -> String {
+    let entries = timings
+        .iter()
+        .map(|t| {
+            format!(
+                "  {{\"day\": {}, \"stage\": {}, \"min\": {}, \"median\": {}, \"p95\": {}}}",
+                t.day,
+                json::quote(&t.stage),
+                t.stats.min.as_secs_f64(),
+                t.stats.median.as_secs_f64(),
+                t.stats.p95.as_secs_f64()
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+pub fn from_json(s: &str) -> Result<Vec<Timing>, String> {
+    let entries = match json::parse(s)? {
+        Value::Array(entries) => entries,
+        _ => return Err("Expected array of timings".to_string()),
+    };
+    entries
+        .iter()
+        .map(|e| {
+            let number = |k: &str| {
+                e.get(k)
+                    .and_then(|v| v.as_f64())
+                    .ok_or(format!("Missing field: {}", k))
+            };
+            let secs = |k: &str| number(k).map(Duration::from_secs_f64);
+            Ok(Timing {
+                day: number("day")? as u32,
+                stage: e
+                    .get("stage")
+                    .and_then(|v| v.as_str())
+                    .ok_or("Missing field: stage")?
+                    .to_string(),
+                stats: Stats {
+                    min: secs("min")?,
+                    median: secs("median")?,
+                    p95: secs("p95")?,
+                },
+            })
+        })
+        .collect()
+}
+
+// Percentage change in median time against the matching baseline entry
+pub fn change(timing: &Timing, baseline: &[Timing]) -> Option<f64> {
+    baseline
+        .iter()
+        .find(|b| b.day == timing.day && b.stage == timing.stage)
+        .map(|b| {
+            let (current, base) = (
+                timing.stats.median.as_nanos() as f64,
+                b.stats.median.as_nanos() as f64,
+            );
+            (current - base) / base * 100.0
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(v: &[u64]) -> Vec<Duration> {
+        v.iter().map(|&n| Duration::from_millis(n)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let s = Stats::new(&ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(s.min, Duration::from_millis(1));
+        assert_eq!(s.median, Duration::from_millis(3));
+        assert_eq!(s.p95, Duration::from_millis(5));
+        let s = Stats::new(&ms(&(1..=100).rev().collect::<Vec<_>>()));
+        assert_eq!(s.median, Duration::from_micros(50500));
+        assert_eq!(s.p95, Duration::from_millis(95));
+        let seven = Duration::from_millis(7);
+        assert_eq!(
+            Stats::new(&ms(&[7])),
+            Stats {
+                min: seven,
+                median: seven,
+                p95: seven
+            }
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let timings = vec![
+            Timing {
+                day: 1,
+                stage: "parse".to_string(),
+                stats: Stats::new(&ms(&[1, 2, 3])),
+            },
+            Timing {
+                day: 25,
+                stage: "part1".to_string(),
+                stats: Stats {
+                    min: Duration::from_nanos(1),
+                    median: Duration::from_nanos(123456),
+                    p95: Duration::from_secs(15),
+                },
+            },
+        ];
+        assert_eq!(from_json(&to_json(&timings)), Ok(timings));
+        assert_eq!(from_json("[]"), Ok(vec![]));
+        assert!(from_json("{}").is_err());
+        assert!(from_json("[{\"day\": 1}]").is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let timing = |day, median| Timing {
+            day,
+            stage: "part1".to_string(),
+            stats: Stats::new(&ms(&[median])),
+        };
+        let baseline = vec![timing(1, 100), timing(2, 100)];
+        assert_eq!(change(&timing(1, 150), &baseline), Some(50.0));
+        assert_eq!(change(&timing(2, 50), &baseline), Some(-50.0));
+        assert_eq!(change(&timing(3, 50), &baseline), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+// Minimal JSON reader - enough to load the files written by bench
+// (\uXXXX escapes are not supported)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(HashMap<String, Value>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(o) => o.get(key),
+            _ => None,
+        }
+    }
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse(s: &str) -> Result<Value, String> {
+    let mut chars = s.chars().peekable();
+    let value = parse_value(&mut chars)?;
+    skip_ws(&mut chars);
+    match chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("Unexpected trailing character: {}", c)),
+    }
+}
+
+pub fn quote(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn skip_ws(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, c: char) -> Result<(), String> {
+    skip_ws(chars);
+    match chars.next() {
+        Some(n) if n == c => Ok(()),
+        Some(n) => Err(format!("Expected '{}' found '{}'", c, n)),
+        None => Err(format!("Expected '{}' found end of input", c)),
+    }
+}
+
+fn parse_literal(
+    chars: &mut Peekable<Chars>,
+    literal: &str,
+    value: Value,
+) -> Result<Value, String> {
+    for c in literal.chars() {
+        expect(chars, c)?;
+    }
+    Ok(value)
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut out = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(out),
+            Some('\\') => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(c @ ('"' | '\\' | '/')) => out.push(c),
+                c => return Err(format!("Invalid escape: {:?}", c)),
+            },
+            Some(c) => out.push(c),
+            None => return Err("Unterminated string".to_string()),
+        }
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Result<Value, String> {
+    skip_ws(chars);
+    match chars.peek() {
+        Some('n') => parse_literal(chars, "null", Value::Null),
+        Some('t') => parse_literal(chars, "true", Value::Bool(true)),
+        Some('f') => parse_literal(chars, "false", Value::Bool(false)),
+        Some('"') => Ok(Value::String(parse_string(chars)?)),
+        Some('[') => {
+            chars.next();
+            let mut out = vec![];
+            skip_ws(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Ok(Value::Array(out));
+            }
+            loop {
+                out.push(parse_value(chars)?);
+                skip_ws(chars);
+                match chars.next() {
+                    Some(',') => {}
+                    Some(']') => return Ok(Value::Array(out)),
+                    c => return Err(format!("Expected ',' or ']' found {:?}", c)),
+                }
+            }
+        }
+        Some('{') => {
+            chars.next();
+            let mut out = HashMap::new();
+            skip_ws(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Ok(Value::Object(out));
+            }
+            loop {
+                skip_ws(chars);
+                let k = parse_string(chars)?;
+                expect(chars, ':')?;
+                out.insert(k, parse_value(chars)?);
+                skip_ws(chars);
+                match chars.next() {
+                    Some(',') => {}
+                    Some('}') => return Ok(Value::Object(out)),
+                    c => return Err(format!("Expected ',' or '}}' found {:?}", c)),
+                }
+            }
+        }
+        Some(c) if *c == '-' || c.is_ascii_digit() => {
+            let mut n = String::new();
+            while let Some(c) =
+                chars.next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+            {
+                n.push(c);
+            }
+            n.parse::<f64>()
+                .map(Value::Number)
+                .map_err(|_| format!("Invalid number: {}", n))
+        }
+        c => Err(format!("Unexpected character: {:?}", c)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let v = parse(r#" { "a": [1, -2.5, 1e-3], "b": "x\"y", "c": null, "d": true, "e": {} } "#)
+            .unwrap();
+        assert_eq!(
+            v.get("a"),
+            Some(&Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-2.5),
+                Value::Number(0.001)
+            ]))
+        );
+        assert_eq!(v.get("b").and_then(|v| v.as_str()), Some("x\"y"));
+        assert_eq!(v.get("c"), Some(&Value::Null));
+        assert_eq!(v.get("d"), Some(&Value::Bool(true)));
+        assert_eq!(v.get("e"), Some(&Value::Object(HashMap::new())));
+        assert_eq!(parse("[]"), Ok(Value::Array(vec![])));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("{\"a\" 1}").is_err());
+        assert!(parse("\"abc").is_err());
+        assert!(parse("nul").is_err());
+        assert!(parse("1 2").is_err());
+        assert!(parse("--1").is_err());
+    }
+
+    #[test]
+    fn test_quote() {
+        let s = "a\"b\\c\nd";
+        assert_eq!(parse(&quote(s)), Ok(Value::String(s.to_string())));
+    }
+}
@@ -0,0 +1,516 @@
+mod answers;
+mod bench;
+mod json;
+
+use answers::Answers;
+use bench::{Stats, Timing};
+use std::env;
+use std::fs::File;
+use std::io::ErrorKind::{InvalidData, InvalidInput, Unsupported};
+use std::io::{Error, Read};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use util::runner;
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--input <path|->]
+       aoc verify [<day|all>] [--answers <path>]
+       aoc bench [<day|all>] [--warmup <n>] [--iterations <n>]
+                 [--save <path>] [--baseline <path>] [--threshold <pct>]
+
+    <day>            Day number (1-25) or 'all'
+    --part <n>       Only run part 1 or 2 (default: both)
+    --input <path>   Input file or '-' for stdin (default: dayNN/input.txt)
+    --answers <path> Expected answers manifest (default: answers.toml)
+    --warmup <n>     Untimed runs of each stage before timing (default: 3)
+    --iterations <n> Timed runs of each stage (default: 20)
+    --save <path>    Save timings as JSON
+    --baseline <path> Compare against previously saved timings
+    --threshold <pct> Median slowdown flagged as a regression (default: 10)";
+
+type RunFn = fn(&mut dyn Read, Option<u32>) -> std::io::Result<()>;
+type AnswersFn = fn(&mut dyn Read) -> std::io::Result<Vec<String>>;
+type BenchFn =
+    fn(&mut dyn Read, usize, usize) -> std::io::Result<Vec<(&'static str, Vec<Duration>)>>;
+
+struct Day {
+    run: RunFn,
+    answers: AnswersFn,
+    bench: BenchFn,
+}
+
+macro_rules! days {
+    ($($day:ident::$s:ident),* $(,)?) => {
+        [$(Day {
+            run: runner::run_part::<$day::$s>,
+            answers: runner::answers::<$day::$s>,
+            bench: runner::bench::<$day::$s>,
+        }),*]
+    };
+}
+
+const DAYS: [Day; 25] = days![
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day19::Day19,
+    day20::Day20,
+    day21::Day21,
+    day22::Day22,
+    day23::Day23,
+    day24::Day24,
+    day25::Day25,
+];
+
+// For day arguments None => all days
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        day: Option<u32>,
+        part: Option<u32>,
+        input: Option<String>,
+    },
+    Verify {
+        day: Option<u32>,
+        answers: String,
+    },
+    Bench {
+        day: Option<u32>,
+        warmup: usize,
+        iterations: usize,
+        save: Option<String>,
+        baseline: Option<String>,
+        threshold: f64,
+    },
+}
+
+fn invalid(msg: String) -> Error {
+    Error::new(InvalidInput, msg)
+}
+
+fn parse_day(day: Option<&str>) -> std::io::Result<Option<u32>> {
+    match day {
+        Some("all") => Ok(None),
+        Some(d) => match d.parse::<u32>() {
+            Ok(d @ 1..=25) => Ok(Some(d)),
+            _ => Err(invalid(format!("Invalid day: {}", d))),
+        },
+        None => Err(invalid("Missing day".to_string())),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, v: &str) -> std::io::Result<T> {
+    v.parse::<T>()
+        .map_err(|_| invalid(format!("Invalid value for {}: {}", arg, v)))
+}
+
+fn parse_args(args: &[String]) -> std::io::Result<Command> {
+    let mut args = args.iter().map(|s| s.as_str()).peekable();
+    match args.next() {
+        Some("run") => {
+            let day = parse_day(args.next())?;
+            let mut part = None;
+            let mut input = None;
+            while let Some(arg) = args.next() {
+                match (arg, args.next()) {
+                    ("--part", Some(p)) => match p.parse::<u32>() {
+                        Ok(p @ 1..=2) => part = Some(p),
+                        _ => return Err(invalid(format!("Invalid part: {}", p))),
+                    },
+                    ("--input", Some(i)) if day.is_some() => input = Some(i.to_string()),
+                    ("--input", Some(_)) => {
+                        return Err(invalid("--input needs a single day".to_string()))
+                    }
+                    (a, _) => return Err(invalid(format!("Invalid argument: {}", a))),
+                }
+            }
+            Ok(Command::Run { day, part, input })
+        }
+        Some("verify") => {
+            let day = match args.next_if(|a| !a.starts_with("--")) {
+                Some(d) => parse_day(Some(d))?,
+                None => None,
+            };
+            let mut answers = "answers.toml".to_string();
+            while let Some(arg) = args.next() {
+                match (arg, args.next()) {
+                    ("--answers", Some(a)) => answers = a.to_string(),
+                    (a, _) => return Err(invalid(format!("Invalid argument: {}", a))),
+                }
+            }
+            Ok(Command::Verify { day, answers })
+        }
+        Some("bench") => {
+            let day = match args.next_if(|a| !a.starts_with("--")) {
+                Some(d) => parse_day(Some(d))?,
+                None => None,
+            };
+            let (mut warmup, mut iterations, mut threshold) = (3, 20, 10.0);
+            let (mut save, mut baseline) = (None, None);
+            while let Some(arg) = args.next() {
+                match (arg, args.next()) {
+                    ("--warmup", Some(n)) => warmup = parse_number(arg, n)?,
+                    ("--iterations", Some(n)) => iterations = parse_number(arg, n)?,
+                    ("--threshold", Some(n)) => threshold = parse_number(arg, n)?,
+                    ("--save", Some(p)) => save = Some(p.to_string()),
+                    ("--baseline", Some(p)) => baseline = Some(p.to_string()),
+                    (a, _) => return Err(invalid(format!("Invalid argument: {}", a))),
+                }
+            }
+            if iterations == 0 {
+                return Err(invalid("--iterations must be at least 1".to_string()));
+            }
+            Ok(Command::Bench {
+                day,
+                warmup,
+                iterations,
+                save,
+                baseline,
+                threshold,
+            })
+        }
+        Some(cmd) => Err(invalid(format!("Unknown command: {}", cmd))),
+        None => Err(invalid("Missing command".to_string())),
+    }
+}
+
+fn open_input(day: u32, input: Option<&str>) -> std::io::Result<Box<dyn Read>> {
+    match input {
+        Some("-") => Ok(Box::new(std::io::stdin().lock())),
+        Some(path) => Ok(Box::new(File::open(path)?)),
+        None => Ok(Box::new(File::open(format!("day{:02}/input.txt", day))?)),
+    }
+}
+
+fn days(day: Option<u32>) -> Vec<u32> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=DAYS.len() as u32).collect(),
+    }
+}
+
+fn run(day: Option<u32>, part: Option<u32>, input: Option<&str>) -> std::io::Result<()> {
+    match day {
+        Some(day) => (DAYS[day as usize - 1].run)(&mut open_input(day, input)?, part),
+        None => {
+            let start = Instant::now();
+            for day in days(None) {
+                println!("=== day{:02}", day);
+                run_all_day(day, part, &mut open_input(day, None)?)?;
+            }
+            println!("Total: {}s", start.elapsed().as_secs_f32());
+            Ok(())
+        }
+    }
+}
+
+// Run one day for run all - days without the part asked for are skipped
+// (it's only an error when the day is given explicitly)
+fn run_all_day(day: u32, part: Option<u32>, input: &mut dyn Read) -> std::io::Result<()> {
+    match (DAYS[day as usize - 1].run)(input, part) {
+        Err(e) if e.kind() == Unsupported => {
+            println!("Part{}: n/a", part.unwrap_or(2));
+            Ok(())
+        }
+        r => r,
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Summary {
+    pass: usize,
+    fail: usize,
+    missing: usize,
+}
+
+// Compare the results for a day against the manifest (printing a line per part)
+fn check(day: u32, results: std::io::Result<Vec<String>>, answers: &Answers) -> Summary {
+    let mut summary = Summary::default();
+    let results = match results {
+        Ok(results) => results,
+        Err(e) => {
+            println!("day{:02}: FAIL ({})", day, e);
+            summary.fail += 1;
+            return summary;
+        }
+    };
+    for (i, result) in results.iter().enumerate() {
+        let part = i as u32 + 1;
+        match answers.get(day, part) {
+            Some(expected) if expected == result => {
+                println!("day{:02} part{}: PASS {}", day, part, result);
+                summary.pass += 1;
+            }
+            Some(expected) => {
+                println!(
+                    "day{:02} part{}: FAIL expected {} got {}",
+                    day, part, expected, result
+                );
+                summary.fail += 1;
+            }
+            None => {
+                println!("day{:02} part{}: MISSING got {}", day, part, result);
+                summary.missing += 1;
+            }
+        }
+    }
+    summary
+}
+
+fn verify(day: Option<u32>, answers: &str) -> std::io::Result<bool> {
+    let answers = Answers::parse(&mut File::open(answers)?)?;
+    let mut total = Summary::default();
+    for day in days(day) {
+        let results =
+            open_input(day, None).and_then(|mut f| (DAYS[day as usize - 1].answers)(&mut f));
+        let summary = check(day, results, &answers);
+        total.pass += summary.pass;
+        total.fail += summary.fail;
+        total.missing += summary.missing;
+    }
+    println!(
+        "Passed: {} Failed: {} Missing: {}",
+        total.pass, total.fail, total.missing
+    );
+    Ok(total.fail == 0)
+}
+
+fn bench(
+    day: Option<u32>,
+    (warmup, iterations): (usize, usize),
+    save: Option<&str>,
+    baseline: Option<&str>,
+    threshold: f64,
+) -> std::io::Result<bool> {
+    let baseline = match baseline {
+        Some(path) => bench::from_json(&std::fs::read_to_string(path)?)
+            .map_err(|e| Error::new(InvalidData, format!("{}: {}", path, e)))?,
+        None => vec![],
+    };
+    let mut timings = vec![];
+    let mut regressions = 0;
+    for day in days(day) {
+        let stages =
+            (DAYS[day as usize - 1].bench)(&mut open_input(day, None)?, warmup, iterations)?;
+        for (stage, samples) in stages {
+            let timing = Timing {
+                day,
+                stage: stage.to_string(),
+                stats: Stats::new(&samples),
+            };
+            let change = match bench::change(&timing, &baseline) {
+                Some(c) if c > threshold => {
+                    regressions += 1;
+                    format!(" ({:+.1}%) REGRESSION", c)
+                }
+                Some(c) => format!(" ({:+.1}%)", c),
+                None => String::new(),
+            };
+            println!(
+                "day{:02} {}: min {:.3?} median {:.3?} p95 {:.3?}{}",
+                day, stage, timing.stats.min, timing.stats.median, timing.stats.p95, change
+            );
+            timings.push(timing);
+        }
+    }
+    if let Some(path) = save {
+        std::fs::write(path, bench::to_json(&timings))?;
+    }
+    if !baseline.is_empty() {
+        println!("Regressions: {} (threshold {}%)", regressions, threshold);
+    }
+    Ok(regressions == 0)
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let result = match command {
+        Command::Run { day, part, input } => run(day, part, input.as_deref()).map(|_| true),
+        Command::Verify { day, answers } => verify(day, &answers),
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => bench(
+            day,
+            (warmup, iterations),
+            save.as_deref(),
+            baseline.as_deref(),
+            threshold,
+        ),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> std::io::Result<Command> {
+        parse_args(&s.split_whitespace().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args("run 17 --part 2 --input test.txt").unwrap(),
+            Command::Run {
+                day: Some(17),
+                part: Some(2),
+                input: Some("test.txt".to_string())
+            }
+        );
+        assert_eq!(
+            args("run all").unwrap(),
+            Command::Run {
+                day: None,
+                part: None,
+                input: None
+            }
+        );
+        assert!(args("").is_err());
+        assert!(args("build 1").is_err());
+        assert!(args("run").is_err());
+        assert!(args("run 26").is_err());
+        assert!(args("run 1 --part 3").is_err());
+        assert!(args("run 1 --part").is_err());
+        assert!(args("run all --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_verify() {
+        assert_eq!(
+            args("verify").unwrap(),
+            Command::Verify {
+                day: None,
+                answers: "answers.toml".to_string()
+            }
+        );
+        assert_eq!(
+            args("verify 5 --answers a.toml").unwrap(),
+            Command::Verify {
+                day: Some(5),
+                answers: "a.toml".to_string()
+            }
+        );
+        assert_eq!(
+            args("verify --answers a.toml").unwrap(),
+            Command::Verify {
+                day: None,
+                answers: "a.toml".to_string()
+            }
+        );
+        assert!(args("verify 0").is_err());
+        assert!(args("verify --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        assert_eq!(
+            args("bench 3 --iterations 5 --save out.json --baseline base.json --threshold 2.5")
+                .unwrap(),
+            Command::Bench {
+                day: Some(3),
+                warmup: 3,
+                iterations: 5,
+                save: Some("out.json".to_string()),
+                baseline: Some("base.json".to_string()),
+                threshold: 2.5
+            }
+        );
+        assert_eq!(
+            args("bench --warmup 0").unwrap(),
+            Command::Bench {
+                day: None,
+                warmup: 0,
+                iterations: 20,
+                save: None,
+                baseline: None,
+                threshold: 10.0
+            }
+        );
+        assert!(args("bench --iterations 0").is_err());
+        assert!(args("bench --warmup x").is_err());
+        assert!(args("bench 1 --save").is_err());
+    }
+
+    #[test]
+    fn test_run_reader() {
+        let mut input = "1abc2\npqr3stu8vwx\n".as_bytes();
+        assert!((DAYS[0].run)(&mut input, Some(1)).is_ok());
+    }
+
+    #[test]
+    fn test_run_part2_only() {
+        // Day 25 has no part 2
+        let input = "jqt: rhn xhk\nrhn: xhk\n";
+        assert!((DAYS[24].run)(&mut input.as_bytes(), Some(2)).is_err());
+        assert!(run_all_day(25, Some(2), &mut input.as_bytes()).is_ok());
+        assert!(run_all_day(1, Some(2), &mut "two1nine\n".as_bytes()).is_ok());
+        assert!(run_all_day(1, Some(3), &mut "two1nine\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(&mut "[day01]\npart1 = 3\npart2 = 4\n".as_bytes()).unwrap();
+        let results = (DAYS[0].answers)(&mut "1abc2\n".as_bytes());
+        assert_eq!(
+            check(1, results, &answers),
+            Summary {
+                pass: 0,
+                fail: 2,
+                missing: 0
+            }
+        );
+        let answers = Answers::parse(&mut "[day01]\npart1 = 33\n".as_bytes()).unwrap();
+        let results = (DAYS[0].answers)(&mut "1a\n2b\n".as_bytes());
+        assert_eq!(
+            check(1, results, &answers),
+            Summary {
+                pass: 1,
+                fail: 0,
+                missing: 1
+            }
+        );
+        let results = Err(invalid("No input".to_string()));
+        assert_eq!(
+            check(1, results, &answers),
+            Summary {
+                pass: 0,
+                fail: 1,
+                missing: 0
+            }
+        );
+    }
+}
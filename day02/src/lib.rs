@@ -0,0 +1,129 @@
+#![allow(unused)]
+
+use std::cmp::max;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::parse;
+use util::runner::Solution;
+
+#[derive(Debug, Clone)]
+struct Draw {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: usize,
+    draws: Vec<Draw>,
+}
+
+type In = Vec<Game>;
+type Out = usize;
+const PART1_RESULT: Out = 8;
+const PART2_RESULT: Out = 2286;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::lines(input, |l| {
+        let (id, revealed) = l.split_once(": ")?;
+        let id = id.strip_prefix("Game ")?.parse::<usize>()?;
+        let mut draws: Vec<Draw> = Vec::new();
+        for r in revealed.split(&[';']) {
+            let mut d = Draw {
+                red: 0,
+                green: 0,
+                blue: 0,
+            };
+            for c in r.split(&[',']) {
+                let (n, colour) = c.trim().split_once(" ")?;
+                match colour.text {
+                    "red" => d.red += n.parse::<usize>()?,
+                    "blue" => d.blue += n.parse::<usize>()?,
+                    "green" => d.green += n.parse::<usize>()?,
+                    _ => return Err(colour.error("invalid colour")),
+                }
+            }
+            draws.push(d);
+        }
+        Ok(Game { id, draws })
+    })
+}
+
+fn part1(input: &In) -> Out {
+    let mut result: usize = 0;
+    for Game { id, draws } in input {
+        let mut possible = true;
+        for Draw { red, green, blue } in draws {
+            if *red > 12 || *green > 13 || *blue > 14 {
+                possible = false;
+                break;
+            }
+        }
+        if possible {
+            result += id;
+        }
+    }
+    result
+}
+
+fn part2(input: &In) -> Out {
+    let mut result: usize = 0;
+    for Game { id, draws } in input {
+        let mut needed = Draw {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        for Draw { red, green, blue } in draws {
+            needed.red = max(needed.red, *red);
+            needed.blue = max(needed.blue, *blue);
+            needed.green = max(needed.green, *green);
+        }
+        result += needed.red * needed.blue * needed.green;
+    }
+    result
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
@@ -0,0 +1,123 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::grid::Grid;
+use util::point::Point;
+use util::runner::Solution;
+
+type In = Grid<char>;
+type Out = u32;
+const PART1_RESULT: Out = 4361;
+const PART2_RESULT: Out = 467835;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    Grid::from_reader(input, |b| {
+        b.is_ascii_graphic()
+            .then_some(b as char)
+            .ok_or("invalid cell")
+    })
+}
+
+fn find_parts(input: &In) -> Vec<(u32, Vec<Point>)> {
+    input
+        .runs(|c| c.is_ascii_digit())
+        .into_iter()
+        // Part numbers are next to a symbol
+        .filter(|(points, _)| {
+            input
+                .run_neighbours(points)
+                .iter()
+                .any(|p| !matches!(input.get(p), Some('0'..='9' | '.')))
+        })
+        .map(|(points, digits)| {
+            let n = digits
+                .iter()
+                .fold(0, |n, c| n * 10 + c.to_digit(10).unwrap());
+            (n, points)
+        })
+        .collect()
+}
+
+fn find_gears(input: &In) -> Vec<Point> {
+    let mut gears: Vec<Point> = Vec::new();
+    for y in 0..input.size.dy {
+        for x in 0..input.size.dx {
+            let p = Point::new(x, y);
+            if input.get(&p).unwrap() == &'*' {
+                gears.push(p);
+            }
+        }
+    }
+    gears
+}
+
+fn part1(input: &In) -> Out {
+    find_parts(input).iter().map(|(n, _)| n).sum()
+}
+
+fn part2(input: &In) -> Out {
+    let mut out: u32 = 0;
+    let parts = find_parts(input);
+    for g in find_gears(input) {
+        let adjacent = g.adjacent8().collect::<Vec<_>>();
+        // Parts touching gear
+        let touch = parts
+            .iter()
+            .filter(|(_, p_cover)| p_cover.iter().any(|p| adjacent.contains(p)))
+            .map(|(n, _)| *n)
+            .collect::<Vec<_>>();
+        if touch.len() == 2 {
+            out += touch[0] * touch[1];
+        }
+    }
+    out
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
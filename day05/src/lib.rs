@@ -0,0 +1,246 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use std::ops::Range;
+use util::parse;
+use util::runner::Solution;
+
+type In = (Vec<i64>, Vec<Vec<(i64, i64, Range<i64>)>>);
+type Out = i64;
+const PART1_RESULT: Out = 35;
+const PART2_RESULT: Out = 46;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let mut seeds: Vec<i64> = Vec::new();
+    let chain = parse::sections(input, |s| {
+        if let Ok(n) = s[0].strip_prefix("seeds:") {
+            seeds = n.numbers::<i64>()?;
+            return Ok(None);
+        }
+        s[0].strip_suffix("map:")?;
+        let mut map = s[1..]
+            .iter()
+            .map(|l| match l.numbers::<i64>()?.as_slice() {
+                [dest, source, length] => {
+                    Ok((*source, *dest - *source, *source..*source + *length))
+                }
+                _ => Err(l.error("expected dest/source/length")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        map.sort_by_key(|s| s.0);
+        Ok(Some(map))
+    })?;
+    Ok((seeds, chain.into_iter().flatten().collect::<Vec<_>>()))
+}
+
+fn map_single(input: i64, chain: &[Vec<(i64, i64, Range<i64>)>]) -> i64 {
+    let mut v = input;
+    for map in chain.iter() {
+        for (start, offset, range) in map.iter() {
+            if range.contains(&v) {
+                v += offset;
+                break;
+            }
+        }
+    }
+    v
+}
+
+fn map_range(
+    (seed_start, seed_end): (i64, i64),
+    map: &[(i64, i64, Range<i64>)],
+) -> Vec<(i64, i64)> {
+    let mut out: Vec<(i64, i64)> = Vec::new();
+    let mut seed_start = seed_start;
+    let mut end = false;
+
+    for (start, offset, range) in map.iter() {
+        match (range.contains(&seed_start), range.contains(&seed_end)) {
+            (true, true) => {
+                out.push((seed_start + offset, seed_end + offset));
+                end = true;
+                break;
+            }
+            (true, false) => {
+                out.push((seed_start + offset, range.end - 1 + offset));
+                seed_start = range.end;
+            }
+            (false, true) => {
+                out.push((seed_start, range.start - 1));
+                out.push((range.start + offset, seed_end + offset));
+                end = true;
+                break;
+            }
+            (false, false) => {
+                if seed_end < range.start {
+                    out.push((seed_start, seed_end));
+                    end = true;
+                    break;
+                } else {
+                    // Check against next map block
+                }
+            }
+        }
+    }
+    if !end {
+        out.push((seed_start, seed_end));
+    }
+    out.sort_by_key(|p| p.0);
+    out
+}
+
+fn merge_range(range: &Vec<i64>) -> Vec<(i64, i64)> {
+    let mut out: Vec<(i64, i64)> = Vec::new();
+    let mut r = (0, 0);
+    for &i in range {
+        if i == r.1 + 1 {
+            r = (r.0, i)
+        } else {
+            if r != (0, 0) {
+                out.push(r);
+            }
+            r = (i, i);
+        }
+    }
+    out.push(r);
+    out.sort();
+    out
+}
+
+fn count(v: &[(i64, i64)]) -> i64 {
+    let mut count: i64 = 0;
+    v.iter().for_each(|(a, b)| count += (b - a + 1));
+    count
+}
+
+fn part1((seeds, chain): &In) -> Out {
+    seeds.iter().map(|s| map_single(*s, chain)).min().unwrap()
+}
+
+fn part2_test((seeds, chain): &In) -> Out {
+    println!("{:?}", chain.iter().map(|m| m.len()).collect::<Vec<_>>());
+    let start = 3281178213;
+    let mut ranges: Vec<(i64, i64)> = vec![(start - 1000, start + 1000)];
+    println!(":: MAP SINGLE");
+    for map in chain.iter() {
+        let mut new: Vec<(i64, i64)> = vec![];
+        for (r1, r2) in &ranges {
+            let map = (*r1..*r2 + 1)
+                .map(|v| {
+                    let mut x = v;
+                    for (start, offset, range) in map.iter() {
+                        if range.contains(&v) {
+                            x = v + offset;
+                            break;
+                        }
+                    }
+                    x
+                })
+                .collect::<Vec<_>>();
+            new.append(&mut merge_range(&map));
+        }
+        println!("{:?}", new);
+        ranges = new;
+    }
+    println!(":: MAP RANGE");
+    let mut seeds: Vec<(i64, i64)> = vec![(start - 1000, start + 1000)];
+    for map in chain.iter() {
+        seeds = seeds
+            .iter()
+            .flat_map(|s| map_range(*s, map))
+            .collect::<Vec<_>>();
+        seeds.sort_by_key(|p| p.0);
+        println!(">> {:?}", seeds);
+    }
+    0
+}
+
+// XXX This doesnt work...
+fn part2((seeds, chain): &In) -> Out {
+    let mut seeds: Vec<(i64, i64)> = seeds
+        .as_slice()
+        .chunks_exact(2)
+        .map(|c| (c[0], c[0] + c[1] - 1))
+        .collect();
+    seeds.sort_by_key(|p| p.0);
+    for map in chain.iter() {
+        seeds = seeds
+            .iter()
+            .flat_map(|s| map_range(*s, map))
+            .collect::<Vec<_>>();
+        seeds.sort_by_key(|p| p.0);
+    }
+    seeds.first().unwrap().0
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
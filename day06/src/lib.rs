@@ -0,0 +1,117 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use std::iter::zip;
+use util::parse::{self, ParseError, Span};
+use util::runner::Solution;
+
+type In = Vec<(u64, u64)>;
+type Out = u64;
+const PART1_RESULT: Out = 288;
+const PART2_RESULT: Out = 71503;
+
+// Parse the Time/Distance lines with f
+fn parse_races<T>(
+    input: &mut impl Read,
+    f: impl Fn(Span) -> Result<T, ParseError>,
+) -> std::io::Result<(T, T)> {
+    let mut lines = parse::lines(input, |l| match l.line {
+        1 => f(l.strip_prefix("Time:")?),
+        2 => f(l.strip_prefix("Distance:")?),
+        _ => Err(l.error("unexpected line")),
+    })?;
+    match (lines.pop(), lines.pop()) {
+        (Some(distance), Some(times)) => Ok((times, distance)),
+        _ => Err(Error::new(InvalidData, "expected Time and Distance lines")),
+    }
+}
+
+fn parse_input1(input: &mut impl Read) -> std::io::Result<In> {
+    let (times, distance) = parse_races(input, |l| l.numbers::<u64>())?;
+    Ok(zip(times, distance).collect::<Vec<_>>())
+}
+
+fn parse_input2(input: &mut impl Read) -> std::io::Result<In> {
+    // Ignore spaces between digits
+    let (times, distance) = parse_races(input, |l| {
+        l.text
+            .chars()
+            .filter(|c| *c != ' ')
+            .try_fold(0_u64, |acc, c| match c.to_digit(10) {
+                Some(d) => Ok(acc * 10 + d as u64),
+                None => Err(l.error("expected digits")),
+            })
+    })?;
+    Ok(vec![(times, distance)])
+}
+
+fn part1(input: &In) -> Out {
+    let mut result: Vec<u64> = Vec::new();
+    for (time, distance) in input {
+        result.push(0);
+        for h in 1..*time {
+            if h * (time - h) > *distance {
+                let mut r = result.last_mut().unwrap();
+                *r += 1;
+            }
+        }
+    }
+    result.iter().product()
+}
+
+fn part2(input: &In) -> Out {
+    let (time, distance) = input.first().unwrap();
+    let mut result = 0;
+    for h in 1..*time {
+        if h * (time - h) > *distance {
+            result += 1;
+        }
+    }
+    result
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (In, In);
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<(In, In)> {
+        let mut data = Vec::new();
+        input.read_to_end(&mut data)?;
+        Ok((
+            parse_input1(&mut data.as_slice())?,
+            parse_input2(&mut data.as_slice())?,
+        ))
+    }
+    fn part1(input: &(In, In)) -> Out {
+        part1(&input.0)
+    }
+    fn part2(input: &(In, In)) -> Option<Out> {
+        Some(part2(&input.1))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input1(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input2(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+Time:      7  15   30
+Distance:  9  40  200
+";
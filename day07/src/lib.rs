@@ -0,0 +1,104 @@
+#![allow(unused)]
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::combinations::combinations;
+use util::parse;
+use util::runner::Solution;
+
+mod part1;
+mod part2;
+
+type In = Vec<(Vec<char>, u32)>;
+type Out = u32;
+const PART1_RESULT: Out = 6440;
+const PART2_RESULT: Out = 5905;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::lines(input, |l| {
+        let (h, b) = l.split_once(" ")?;
+        let cards = h.cells(|c| "23456789TJQKA".contains(c).then_some(c))?;
+        let bid = b.parse::<u32>()?;
+        Ok((cards, bid))
+    })
+}
+
+fn remove(cards: &[char], card: char) -> Vec<char> {
+    cards
+        .iter()
+        .cloned()
+        .filter(|&c| c != card)
+        .collect::<Vec<_>>()
+}
+
+fn part1(input: &In) -> Out {
+    let mut hands = input
+        .iter()
+        .map(|(cards, bid)| (part1::Hand::from(cards), *bid))
+        .collect::<Vec<_>>();
+    hands.sort_by_key(|(c, _)| c.clone());
+    let winnings: u32 = hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, b))| (i + 1) as u32 * *b)
+        .sum();
+    winnings
+}
+
+fn part2(input: &In) -> Out {
+    let mut hands = input
+        .iter()
+        .map(|(cards, bid)| (part2::Hand::from(cards), *bid))
+        .collect::<Vec<_>>();
+    hands.sort_by_key(|(c, _)| c.clone());
+    let winnings: u32 = hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, b))| (i + 1) as u32 * *b)
+        .sum();
+    winnings
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
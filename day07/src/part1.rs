@@ -13,7 +13,7 @@ fn same(cards: &Vec<char>) -> Option<char> {
             return None;
         }
     }
-    Some(first.clone())
+    Some(*first)
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -21,19 +21,19 @@ pub struct Card(char);
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.0 == other.0 {
-            Some(Ordering::Equal)
-        } else {
-            let index_self = ORDER.iter().position(|&c| c == self.0).unwrap();
-            let index_other = ORDER.iter().position(|&c| c == other.0).unwrap();
-            index_self.partial_cmp(&index_other)
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Card {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        if self.0 == other.0 {
+            Ordering::Equal
+        } else {
+            let index_self = ORDER.iter().position(|&c| c == self.0).unwrap();
+            let index_other = ORDER.iter().position(|&c| c == other.0).unwrap();
+            index_self.cmp(&index_other)
+        }
     }
 }
 
@@ -47,7 +47,7 @@ impl Ord for Cards {
                 return a.cmp(&b);
             }
         }
-        return Ordering::Equal;
+        Ordering::Equal
     }
 }
 
@@ -72,7 +72,7 @@ impl From<&Vec<char>> for Hand {
     fn from(cards: &Vec<char>) -> Self {
         let hand = Cards(cards.iter().map(|c| Card(*c)).collect::<Vec<_>>());
         if let Some(c) = same(cards) {
-            return Hand::Fives(hand);
+            Hand::Fives(hand)
         } else {
             for fours in &combinations(cards, 4) {
                 if let Some(c) = same(fours) {
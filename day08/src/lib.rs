@@ -0,0 +1,142 @@
+#![allow(unused)]
+
+use num::integer::lcm;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::parse;
+use util::runner::Solution;
+
+type In = (Vec<char>, HashMap<String, (String, String)>);
+type Out = usize;
+const PART1_RESULT: Out = 2;
+const PART2_RESULT: Out = 6;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let mut turns: Vec<char> = Vec::new();
+    let mut nodes: HashMap<String, (String, String)> = HashMap::new();
+    parse::lines(input, |l| {
+        match l.line {
+            1 => turns = l.cells(|c| matches!(c, 'L' | 'R').then_some(c))?,
+            2 if l.is_empty() => {}
+            _ => {
+                let (k, v) = l.split_once(" = ")?;
+                let (a, b) = v.strip_prefix("(")?.strip_suffix(")")?.split_once(", ")?;
+                nodes.insert(k.text.to_string(), (a.text.to_string(), b.text.to_string()));
+            }
+        }
+        Ok(())
+    })?;
+    Ok((turns, nodes))
+}
+
+fn part1((turns, nodes): &In) -> Out {
+    let mut current = "AAA".to_string();
+    let mut result: usize = 0;
+    for (i, &d) in turns.iter().cycle().enumerate() {
+        current = if d == 'L' {
+            nodes[&current].0.clone()
+        } else {
+            nodes[&current].1.clone()
+        };
+        if current == "ZZZ" {
+            result = i + 1;
+            break;
+        }
+    }
+    result
+}
+
+fn part2((turns, nodes): &In) -> Out {
+    let mut result: usize = 0;
+    let mut current = nodes
+        .keys()
+        .filter(|&k| k.ends_with('A'))
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut cycle: Vec<usize> = vec![0; current.len()];
+    for (i, &d) in turns.iter().cycle().enumerate() {
+        let mut found_cycle = false;
+        current = current
+            .iter()
+            .map(|c| {
+                if d == 'L' {
+                    nodes[c].0.clone()
+                } else {
+                    nodes[c].1.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        current.iter().enumerate().for_each(|(j, c)| {
+            if c.ends_with('Z') {
+                cycle[j] = i + 1;
+                found_cycle = true;
+            }
+        });
+        if found_cycle && cycle.iter().all(|&i| i != 0) {
+            result = cycle.iter().fold(1_usize, |acc, &i| lcm(acc, i));
+            break;
+        }
+    }
+    result
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA1.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA2.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA1: &str = "
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+#[cfg(test)]
+const TESTDATA2: &str = "
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
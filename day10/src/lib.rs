@@ -0,0 +1,228 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::grid::fill::{Edge, FillError};
+use util::grid::Grid;
+use util::point::*;
+use util::runner::Solution;
+
+type In = Grid<char>;
+type Out = usize;
+const PART1_RESULT: Out = 8;
+const PART2_RESULT1: Out = 4;
+const PART2_RESULT2: Out = 8;
+const PART2_RESULT3: Out = 10;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    // The examples mark inside/outside tiles as I/O
+    Grid::from_reader(input, |b| match b {
+        b'I' | b'O' => Ok('.'),
+        b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' | b'S' => Ok(b as char),
+        _ => Err("invalid pipe"),
+    })
+}
+
+const PIPES: [(char, (Offset, Offset)); 6] = [
+    ('|', (UP, DOWN)),
+    ('-', (LEFT, RIGHT)),
+    ('L', (UP, RIGHT)),
+    ('J', (UP, LEFT)),
+    ('7', (DOWN, LEFT)),
+    ('F', (DOWN, RIGHT)),
+];
+
+fn map_direction(d: Offset, pipe: &char) -> Option<Offset> {
+    // Flip input direction to match with pipe connections
+    let d = match (d) {
+        LEFT => RIGHT,
+        RIGHT => LEFT,
+        UP => DOWN,
+        DOWN => UP,
+        _ => panic!("Invalid Direction"),
+    };
+    if let Some((_, (a, b))) = PIPES.iter().rfind(|&p| *pipe == p.0) {
+        match (d == *a, d == *b) {
+            (true, false) => Some(*b),
+            (false, true) => Some(*a),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+fn find_start(input: &In) -> (Point, Vec<Offset>) {
+    let start = *input.find(&'S').first().unwrap();
+    let direction = ADJACENT
+        .iter()
+        .filter(|&o| {
+            if let Some(p) = input.get(&(start + *o)) {
+                map_direction(*o, p).is_some()
+            } else {
+                false
+            }
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    (start, direction)
+}
+
+fn find_path(input: &In, start: Point, direction: Offset) -> Vec<Point> {
+    let mut out: Vec<Point> = Vec::new();
+    let mut p = start;
+    let mut d = direction;
+    loop {
+        p = p + d;
+        out.push(p);
+        if p == start {
+            break;
+        }
+        d = map_direction(d, input.get(&p).unwrap()).unwrap();
+    }
+    out
+}
+
+fn part1(input: &In) -> Out {
+    let (start, direction) = find_start(input);
+    find_path(input, start, direction[0]).len().div_ceil(2)
+}
+
+// 3x3 block for each pipe so that the outside can be flood filled through
+// the gaps between pipes
+fn expand_pipe(c: &char) -> [[char; 3]; 3] {
+    let mask = match c {
+        '|' => [[0, 1, 0], [0, 1, 0], [0, 1, 0]],
+        '-' => [[0, 0, 0], [1, 1, 1], [0, 0, 0]],
+        'L' => [[0, 1, 0], [0, 1, 1], [0, 0, 0]],
+        'J' => [[0, 1, 0], [1, 1, 0], [0, 0, 0]],
+        '7' => [[0, 0, 0], [1, 1, 0], [0, 1, 0]],
+        'F' => [[0, 0, 0], [0, 1, 1], [0, 1, 0]],
+        'S' => [[1, 1, 1], [1, 1, 1], [1, 1, 1]],
+        _ => [[0, 0, 0], [0, 0, 0], [0, 0, 0]],
+    };
+    mask.map(|row| row.map(|m| if m == 1 { '*' } else { '.' }))
+}
+
+// Fails if the loop covers the top left corner (where the fill starts)
+fn part2_flood_fill(input: &In) -> Result<Out, FillError> {
+    let (start, direction) = find_start(input);
+    let mut clean = Grid::empty(&input.start, &input.end, '.');
+    find_path(input, start, direction[0]).iter().for_each(|&p| {
+        clean.set(&p, *input.get(&p).unwrap()).unwrap();
+    });
+    let mut expanded = clean.upscale(3, expand_pipe);
+    expanded.fill(
+        &expanded.start.clone(),
+        |&c| c == '.',
+        Neighbourhood::VonNeumann,
+        Edge::Stop,
+        'O',
+    )?;
+    // Cells whose centre wasn't reached are inside
+    Ok(expanded.downsample(3, |b| *b[4]).find(&'.').len())
+}
+
+fn part2(input: &In) -> Out {
+    let (start, direction) = find_start(input);
+    input
+        .enclosed_cells(&find_path(input, start, direction[0]))
+        .len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input1 = parse_input(&mut TESTDATA2_1.trim_matches('\n').as_bytes()).unwrap();
+    let input2 = parse_input(&mut TESTDATA2_2.trim_matches('\n').as_bytes()).unwrap();
+    let input3 = parse_input(&mut TESTDATA2_3.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input1), PART2_RESULT1);
+    assert_eq!(part2(&input2), PART2_RESULT2);
+    assert_eq!(part2(&input3), PART2_RESULT3);
+    assert_eq!(part2_flood_fill(&input1), Ok(PART2_RESULT1));
+    assert_eq!(part2_flood_fill(&input2), Ok(PART2_RESULT2));
+    assert_eq!(part2_flood_fill(&input3), Ok(PART2_RESULT3));
+    // Start on the loop
+    let corner = parse_input(&mut "S7\nLJ".as_bytes()).unwrap();
+    assert!(matches!(
+        part2_flood_fill(&corner),
+        Err(FillError::Impassable(_))
+    ));
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
+
+#[cfg(test)]
+const TESTDATA2_1: &str = "
+..........
+.S------7.
+.|F----7|.
+.||OOOO||.
+.||OOOO||.
+.|L-7F-J|.
+.|II||II|.
+.L--JL--J.
+..........
+";
+
+#[cfg(test)]
+const TESTDATA2_2: &str = "
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+#[cfg(test)]
+const TESTDATA2_3: &str = "
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
@@ -0,0 +1,134 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::combinations::combinations;
+use util::grid::Grid;
+use util::point::Point;
+use util::runner::Solution;
+
+type In = Stars;
+type Out = usize;
+const PART1_RESULT: Out = 374;
+const PART2_RESULT1: Out = 1030;
+const PART2_RESULT2: Out = 8410;
+
+#[derive(Debug)]
+pub struct Stars {
+    stars: Vec<(usize, usize)>,
+    empty_x: Vec<usize>,
+    empty_y: Vec<usize>,
+}
+
+fn n_empty(v: usize, s: &[usize]) -> usize {
+    s.iter().take_while(|&&i| i < v).count()
+}
+
+fn manhattan((x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> usize {
+    usize::max(x1, x2) - usize::min(x1, x2) + usize::max(y1, y2) - usize::min(y1, y2)
+}
+
+impl Stars {
+    fn expand(&self, n: usize) -> Vec<(usize, usize)> {
+        self.stars
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    x + n_empty(x, &self.empty_x) * (n - 1),
+                    y + n_empty(y, &self.empty_y) * (n - 1),
+                )
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let g = Grid::from_reader(input, |b| match b {
+        b'#' => Ok(true),
+        b'.' => Ok(false),
+        _ => Err("invalid cell"),
+    })?;
+    Ok(Stars {
+        stars: g
+            .find(&true)
+            .iter()
+            .map(|p| (p.x as usize, p.y as usize))
+            .collect::<Vec<_>>(),
+        // Rows/cols without any stars
+        empty_x: g
+            .cols()
+            .enumerate()
+            .filter_map(|(x, mut c)| c.all(|(_, &star)| !star).then_some(x))
+            .collect::<Vec<_>>(),
+        empty_y: g
+            .rows()
+            .enumerate()
+            .filter_map(|(y, mut r)| r.all(|(_, &star)| !star).then_some(y))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn part1(input: &In) -> Out {
+    let expanded = input.expand(2);
+    combinations(&expanded, 2)
+        .iter()
+        .map(|v| manhattan(v[0], v[1]))
+        .sum()
+}
+
+fn part2(input: &In, n: usize) -> Out {
+    let expanded = input.expand(n);
+    combinations(&expanded, 2)
+        .iter()
+        .map(|v| manhattan(v[0], v[1]))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input, 1000000))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input, 10), PART2_RESULT1);
+    assert_eq!(part2(&input, 100), PART2_RESULT2);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
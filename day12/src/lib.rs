@@ -0,0 +1,163 @@
+#![allow(unused)]
+
+use rayon::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::combinations::combinations;
+use util::parse;
+use util::runner::Solution;
+
+type In = Vec<(Vec<char>, Vec<usize>)>;
+type Out = usize;
+const PART1_RESULT: Out = 21;
+const PART2_RESULT: Out = 525152;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::lines(input, |l| {
+        let (l, r) = l.split_once(" ")?;
+        let springs = l.cells(|c| matches!(c, '.' | '#' | '?').then_some(c))?;
+        let groups = r.numbers::<usize>()?;
+        Ok((springs, groups))
+    })
+}
+
+fn hash_state(a: &[char], b: &[usize]) -> (u64, u64) {
+    let mut h1 = DefaultHasher::new();
+    let mut h2 = DefaultHasher::new();
+    a.iter().for_each(|c| c.hash(&mut h1));
+    b.iter().for_each(|i| i.hash(&mut h1));
+    (h1.finish(), h2.finish())
+}
+
+// Check recursively caching results
+fn check(springs: &[char], groups: &[usize], cache: &mut HashMap<(u64, u64), usize>) -> usize {
+    // No groups left - check if there are any potential springs not used
+    if groups.is_empty() {
+        if springs.contains(&'#') {
+            return 0;
+        } else {
+            return 1;
+        }
+    }
+
+    // Check if we have enough spring positions left for groups
+    if springs.len() < groups.iter().sum() {
+        // Insert state into cache
+        cache.insert(hash_state(springs, groups), 0);
+        return 0;
+    }
+
+    // Check cache
+    if let Some(&result) = cache.get(&hash_state(springs, groups)) {
+        return result;
+    }
+
+    let next_len = groups[0];
+    let mut result: usize = 0;
+
+    // Check next spring:
+    // '.' - step forward
+    // '#' - check if we can match the next group and consume
+    // '?' - do both
+
+    if springs[0] == '.' || springs[0] == '?' {
+        // Cant match group - step forward one position
+        result += check(&springs[1..], groups, cache);
+    }
+    if springs[0] == '#' || springs[0] == '?' {
+        // Check if we can match current group
+        // Look for group of '?' or '#' of correct length followed by
+        // either '.' or '?' or the end of the data
+        if springs[1..next_len].iter().all(|&c| c != '.')
+            && (springs.len() == next_len || springs[next_len] != '#')
+        {
+            // If we can we can step forward the full group length
+            result += check(
+                &springs[(next_len + 1).min(springs.len())..],
+                &groups[1..],
+                cache,
+            );
+        }
+    }
+
+    // Insert state into cache
+    cache.insert(hash_state(springs, groups), result);
+
+    result
+}
+
+fn part1(input: &In) -> Out {
+    input
+        .par_iter()
+        .map(|s| check(s.0.as_slice(), s.1.as_slice(), &mut HashMap::new()))
+        .sum()
+}
+
+fn part2(input: &In) -> Out {
+    input
+        .par_iter()
+        .map(|(s, g)| {
+            let s = s
+                .iter()
+                .cloned()
+                .chain(vec!['?'])
+                .cycle()
+                .take((s.len() + 1) * 5 - 1)
+                .collect::<Vec<_>>();
+            let g = g
+                .iter()
+                .cycle()
+                .take(g.len() * 5)
+                .cloned()
+                .collect::<Vec<_>>();
+            (s, g)
+        })
+        .map(|(s, g)| check(s.as_slice(), g.as_slice(), &mut HashMap::new()))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
@@ -0,0 +1,174 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::grid::Grid;
+use util::parse;
+use util::point::Point;
+use util::runner::Solution;
+
+type In = Vec<Grid<char>>;
+type Out = usize;
+const PART1_RESULT: Out = 405;
+const PART2_RESULT: Out = 400;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::sections(input, |s| {
+        Grid::from_lines(s, |b| match b {
+            b'.' | b'#' => Ok(b as char),
+            _ => Err("invalid cell"),
+        })
+    })
+}
+
+// Check 1D vector for reflections & return reflection point
+fn check_reflection(v: &[usize], ignore: Option<usize>) -> Option<usize> {
+    let len = v.len();
+    for i in (1..len) {
+        let l = &v[(if i > len / 2 { i - (len - i) } else { 0 })..i];
+        let r = &mut v.to_vec()[i..(i + i).min(v.len())];
+        r.reverse();
+        if l == r {
+            if let Some(ignore) = ignore {
+                if i == ignore {
+                    continue;
+                }
+            }
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn to_binary<'a>(v: impl Iterator<Item = (Point, &'a char)>) -> usize {
+    v.fold(
+        0,
+        |acc, (_, &c)| if c == '#' { (acc * 2) + 1 } else { acc * 2 },
+    )
+}
+
+// Flatten 2D binary grid into 2 x 1D usize vectors (rows/columns)
+fn flatten_rows_cols(g: &Grid<char>) -> (Vec<usize>, Vec<usize>) {
+    (
+        g.rows().map(to_binary).collect::<Vec<_>>(),
+        g.cols().map(to_binary).collect::<Vec<_>>(),
+    )
+}
+
+// Simple reflection
+fn find_reflection(p: &Grid<char>) -> Option<usize> {
+    // Flatten grid into 2 x 1D arrays (binary vector -> usize)
+    let (rows, cols) = flatten_rows_cols(p);
+    // Check rows
+    if let Some(r) = check_reflection(&rows, None) {
+        return Some(100 * r);
+    }
+    // Check cols
+    if let Some(c) = check_reflection(&cols, None) {
+        return Some(c);
+    }
+    None
+}
+
+// Reflection with smudge
+fn find_reflection2(p: &Grid<char>) -> Option<usize> {
+    // Flatten grid into 2 x 1D arrays (binary vector -> usize)
+    let (mut rows, mut cols) = flatten_rows_cols(p);
+    // For each element in the 1D flattened vector we
+    // XOR each bit and test for reflection (ignoring
+    // any simple reflection)
+    // (should avoid any reallocations)
+    let ignore = check_reflection(&rows, None);
+    let bits = p.size.dx;
+    for i in (0..rows.len()) {
+        for b in (0..=bits) {
+            rows[i] ^= 1 << b;
+            if let Some(r) = check_reflection(&rows, ignore) {
+                return Some(100 * r);
+            }
+            // Make sure we reset bit
+            rows[i] ^= 1 << b;
+        }
+    }
+    // Do the same for cols
+    let ignore = check_reflection(&cols, None);
+    let bits = p.size.dy;
+    for i in (0..cols.len()) {
+        for b in (0..=bits) {
+            cols[i] ^= 1 << b;
+            if let Some(c) = check_reflection(&cols, ignore) {
+                return Some(c);
+            }
+            cols[i] ^= 1 << b;
+        }
+    }
+    None
+}
+
+fn print_grid(g: &Grid<char>) -> String {
+    g.rows()
+        .map(|r| r.map(|(_, c)| c).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn part1(input: &In) -> Out {
+    input.iter().map(|p| find_reflection(p).unwrap()).sum()
+}
+
+fn part2(input: &In) -> Out {
+    input.iter().map(|p| find_reflection2(p).unwrap()).sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
@@ -0,0 +1,131 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::grid::bits::BitGrid;
+use util::grid::Grid;
+use util::point::*;
+use util::runner::Solution;
+
+type In = Grid<u8>;
+type Out = i64;
+const PART1_RESULT: Out = 136;
+const PART2_RESULT: Out = 64;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    Grid::from_reader(input, |b| match b {
+        b'O' | b'#' | b'.' => Ok(b),
+        _ => Err("invalid cell"),
+    })
+}
+
+// Slide the round rocks as far as they go towards d
+fn tilt_to(input: &mut Grid<u8>, d: Offset) {
+    input.settle(d, |&c| c == b'O', |&c| c == b'#');
+}
+
+fn tilt(input: &Grid<u8>) -> Grid<u8> {
+    let mut tilted = input.clone();
+    tilt_to(&mut tilted, UP);
+    tilted
+}
+
+// Tilt N/W/S/E
+fn cycle(input: &Grid<u8>) -> Grid<u8> {
+    let mut g = input.clone();
+    for d in [UP, LEFT, DOWN, RIGHT] {
+        tilt_to(&mut g, d);
+    }
+    g
+}
+
+fn part1(input: &In) -> Out {
+    let mut tilted = tilt(input);
+    tilted
+        .find(&b'O')
+        .iter()
+        .map(|&p| tilted.size.dy - p.y)
+        .sum::<i64>()
+}
+
+fn part2(input: &In) -> Out {
+    // Only the round rocks move so their positions identify the state
+    let key = |g: &Grid<u8>| BitGrid::from_grid(g, |&c| c == b'O');
+    let mut seen: HashMap<BitGrid, usize> = HashMap::new();
+    let mut count: usize = 0;
+    let mut tilted = input.clone();
+    seen.insert(key(input), 0);
+    loop {
+        tilted = cycle(&tilted);
+        count += 1;
+        let k = key(&tilted);
+        if let Some(start) = seen.get(&k) {
+            // println!("Found cycle: start={} count={}", start, count);
+            let cycle_len = count - start;
+            let cycles_left = 1000000000 - count;
+            let remainder = cycles_left % cycle_len;
+            for _ in 0..remainder {
+                tilted = cycle(&tilted);
+            }
+            break;
+        }
+        seen.insert(k, count);
+    }
+    tilted
+        .find(&b'O')
+        .iter()
+        .map(|&p| tilted.size.dy - p.y)
+        .sum::<i64>()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+    // Tilting only moves round rocks
+    let moved = input.diff(&tilt(&input));
+    assert!(!moved.is_empty());
+    assert!(moved.iter().all(|&(_, a, b)| a == b'O' || b == b'O'));
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
@@ -0,0 +1,138 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::ErrorKind::InvalidData;
+use util::parse::{self, ParseError, Span};
+use util::runner::Solution;
+
+type In = Vec<(Vec<u8>, Operation)>;
+type Out = usize;
+const PART1_RESULT: Out = 1320;
+const PART2_RESULT: Out = 145;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let data = parse::lines(input, |l| {
+        l.list(',')
+            .into_iter()
+            .map(|s| Ok((s.text.as_bytes().to_vec(), operation(s)?)))
+            .collect::<Result<Vec<_>, ParseError>>()
+    })?;
+    Ok(data.into_iter().flatten().collect::<Vec<_>>())
+}
+
+fn hash(k: &[u8]) -> u8 {
+    k.iter().fold(0, |acc, &v| ((acc + v as u32) * 17) % 256) as u8
+}
+
+#[derive(Debug)]
+pub enum Operation {
+    Add(String, u8, u8), // Key, Hash Value, Lens Value
+    Remove(String, u8),  // Key, Hash Value
+}
+
+fn operation(s: Span) -> Result<Operation, ParseError> {
+    let (k, v) = match s.strip_suffix("-") {
+        Ok(k) => (k, None),
+        Err(_) => {
+            let (k, v) = s.split_once("=")?;
+            (k, Some(v.parse::<u8>()?))
+        }
+    };
+    if k.is_empty() {
+        return Err(s.error("missing label"));
+    }
+    let h = hash(k.text.as_bytes());
+    Ok(match v {
+        None => Operation::Remove(k.text.to_string(), h),
+        Some(v) => Operation::Add(k.text.to_string(), h, v),
+    })
+}
+
+fn part1(input: &In) -> Out {
+    input.iter().map(|(s, _)| hash(s) as usize).sum()
+}
+
+fn part2(input: &In) -> Out {
+    let mut boxes: [Vec<(String, u8)>; 256] = (0..256)
+        .map(|_| Vec::new())
+        .collect::<Vec<Vec<(String, u8)>>>()
+        .try_into()
+        .unwrap();
+    input.iter().for_each(|(_, op)| match op {
+        Operation::Add(k, h, v) => {
+            let mut b = boxes.get_mut(*h as usize).unwrap();
+            let mut found = false;
+            b.iter_mut().for_each(|i| {
+                if !found && i.0 == *k {
+                    i.1 = *v;
+                    found = true
+                }
+            });
+            if !found {
+                b.push((k.clone(), *v));
+            }
+        }
+        Operation::Remove(k, h) => {
+            let mut b = boxes.get_mut(*h as usize).unwrap();
+            if let Some(i) = b.iter().position(|i| i.0 == *k) {
+                b.remove(i);
+            }
+        }
+    });
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, b)| {
+            b.iter()
+                .enumerate()
+                .map(move |(s, (_, f))| (i + 1) * (s + 1) * *f as usize)
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_parse_error() {
+    for (s, column) in [("rn=1,cm-,qp=x", 13), ("rn=1,=2", 6), ("rn=1,cm", 6)] {
+        let e = parse_input(&mut s.as_bytes()).unwrap_err();
+        let e = e.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, column));
+    }
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = "
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
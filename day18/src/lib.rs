@@ -0,0 +1,191 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use std::num::ParseIntError;
+#[cfg(test)]
+use util::grid::fill::Edge;
+use util::parse;
+use util::point::*;
+use util::runner::Solution;
+#[cfg(test)]
+use util::sparsegrid::SparseGrid;
+
+type In = Vec<(Offset, usize, String)>;
+type Out = usize;
+const PART1_RESULT: Out = 62;
+const PART2_RESULT: Out = 952408144115;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::lines(input, |l| {
+        let [d, n, colour] = l.fields::<3>(&[' '])?;
+        let d = match d.text {
+            "U" => UP,
+            "D" => DOWN,
+            "L" => LEFT,
+            "R" => RIGHT,
+            _ => return Err(d.error("invalid direction")),
+        };
+        let colour = colour.strip_prefix("(#")?.strip_suffix(")")?;
+        if colour.text.len() != 6 || !colour.text.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(colour.error("expected 6 hex digits"));
+        }
+        Ok((d, n.parse::<usize>()?, colour.text.to_string()))
+    })
+}
+
+// Coordinate compression - each distinct coordinate gets a band one cell
+// wide plus a band for any gap before the next one. Returns the band of
+// each coordinate and the width of every band
+#[cfg(test)]
+fn bands(coords: impl Iterator<Item = i64>) -> (HashMap<i64, i64>, Vec<i64>) {
+    let mut coords = coords.collect::<Vec<_>>();
+    coords.sort();
+    coords.dedup();
+    let mut index = HashMap::new();
+    let mut widths = vec![];
+    for (i, &c) in coords.iter().enumerate() {
+        index.insert(c, widths.len() as i64);
+        widths.push(1);
+        match coords.get(i + 1) {
+            Some(&next) if next - c > 1 => widths.push(next - c - 1),
+            _ => {}
+        }
+    }
+    (index, widths)
+}
+
+// Cross-check for shoelace (only used by the tests) - draw the trench on a
+// compressed grid and flood fill outside it (with a border so the outside is
+// connected). Each cell counts for the area of its bands
+#[cfg(test)]
+fn area(points: &[Point]) -> usize {
+    let (xs, widths) = bands(points.iter().map(|p| p.x));
+    let (ys, heights) = bands(points.iter().map(|p| p.y));
+    let mut trench = SparseGrid::new();
+    points.windows(2).for_each(|p| {
+        let [p1, p2] = [p[0], p[1]].map(|p| Point::new(xs[&p.x], ys[&p.y]));
+        trench.draw_line(&p1, &p2, '#').unwrap()
+    });
+    let grid = trench.to_grid('.').pad(1, '.');
+    let outside = grid
+        .flood(
+            &grid.start,
+            |&c| c == '.',
+            Neighbourhood::VonNeumann,
+            Edge::Stop,
+        )
+        .unwrap();
+    // The border cells are outside the bands
+    let band = |v: &[i64], i: i64| usize::try_from(i).ok().and_then(|i| v.get(i)).copied();
+    let outside = outside
+        .iter()
+        .filter_map(|p| Some(band(&widths, p.x)? * band(&heights, p.y)?))
+        .sum::<i64>();
+    (widths.iter().sum::<i64>() * heights.iter().sum::<i64>() - outside) as usize
+}
+
+// Corners of the trench (a closed loop starting at 0,0)
+fn corners(steps: impl Iterator<Item = (Offset, i64)>) -> Vec<Point> {
+    let mut p = Point::new(0, 0);
+    let mut points = vec![p];
+    for (d, n) in steps {
+        p = p + d * n;
+        points.push(p);
+    }
+    points
+}
+
+// Part 2 step hidden in the colour
+fn decode(h: &str) -> (Offset, i64) {
+    let n = i64::from_str_radix(&h[0..5], 16).unwrap();
+    let d = match h.chars().last().unwrap() {
+        '0' => RIGHT,
+        '1' => DOWN,
+        '2' => LEFT,
+        '3' => UP,
+        _ => panic!("Invalid direction"),
+    };
+    (d, n)
+}
+
+// points should be closed loop
+fn shoelace(points: &[Point]) -> usize {
+    assert!(!points.is_empty());
+    assert_eq!(points[0], points[points.len() - 1]);
+    let a = points
+        .windows(2)
+        .fold(0, |acc, p| acc + (p[0].x * p[1].y - p[1].x * p[0].y));
+    let l = points.windows(2).fold(0, |acc, p| {
+        acc + if (p[0].x == p[1].x) {
+            i64::max(p[0].y, p[1].y) - i64::min(p[0].y, p[1].y)
+        } else {
+            i64::max(p[0].x, p[1].x) - i64::min(p[0].x, p[1].x)
+        }
+    });
+    (a.abs() + l) as usize / 2 + 1
+}
+
+fn part1(input: &In) -> Out {
+    shoelace(&corners(input.iter().map(|&(d, n, _)| (d, n as i64))))
+}
+
+fn part2(input: &In) -> Out {
+    shoelace(&corners(input.iter().map(|(_, _, h)| decode(h))))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+    let points = corners(input.iter().map(|&(d, n, _)| (d, n as i64)));
+    assert_eq!(area(&points), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+    let points = corners(input.iter().map(|(_, _, h)| decode(h)));
+    assert_eq!(area(&points), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = r"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
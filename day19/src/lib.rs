@@ -0,0 +1,430 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use std::ops::Range;
+use util::parse;
+use util::runner::Solution;
+
+type In = (Vec<Part>, HashMap<String, Vec<Opcode>>);
+type Out = usize;
+const PART1_RESULT: Out = 19114;
+const PART2_RESULT: Out = 167409079868000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Part {
+    x: i32,
+    m: i32,
+    a: i32,
+    s: i32,
+}
+
+fn value(s: &str, name: &str) -> Result<i32, &'static str> {
+    s.strip_prefix(name)
+        .ok_or("Invalid rating")?
+        .parse::<i32>()
+        .map_err(|e| "ParseIntError")
+}
+
+impl TryFrom<&str> for Part {
+    type Error = &'static str;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let parts = s
+            .trim_matches(|c| c == '{' || c == '}')
+            .split(',')
+            .collect::<Vec<_>>();
+        if parts.len() != 4 {
+            Err("Invalid part")
+        } else {
+            Ok(Part {
+                x: value(parts[0], "x=")?,
+                m: value(parts[1], "m=")?,
+                a: value(parts[2], "a=")?,
+                s: value(parts[3], "s=")?,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Dest {
+    Accept,
+    Reject,
+    Rule(String),
+}
+
+impl From<&str> for Dest {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Dest::Accept,
+            "R" => Dest::Reject,
+            _ => Dest::Rule(s.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Test {
+    Less(i32),
+    Greater(i32),
+}
+
+impl Test {
+    fn run(&self, v: i32) -> bool {
+        match self {
+            Test::Less(n) => &v < n,
+            Test::Greater(n) => &v > n,
+        }
+    }
+}
+
+impl TryFrom<&str> for Test {
+    type Error = &'static str;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.split_at_checked(1) {
+            Some(("<", n)) => Ok(Test::Less(n.parse::<i32>().map_err(|e| "ParseIntError")?)),
+            Some((">", n)) => Ok(Test::Greater(
+                n.parse::<i32>().map_err(|e| "ParseIntError")?,
+            )),
+            _ => Err("Cant parse test"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Opcode {
+    JX(Test, Dest),
+    JM(Test, Dest),
+    JA(Test, Dest),
+    JS(Test, Dest),
+    J(Dest),
+}
+
+impl TryFrom<&str> for Opcode {
+    type Error = &'static str;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if let Some((a, b)) = s.split_once(':') {
+            match a.split_at_checked(1) {
+                Some(("x", t)) => Ok(Opcode::JX(Test::try_from(t)?, Dest::from(b))),
+                Some(("m", t)) => Ok(Opcode::JM(Test::try_from(t)?, Dest::from(b))),
+                Some(("a", t)) => Ok(Opcode::JA(Test::try_from(t)?, Dest::from(b))),
+                Some(("s", t)) => Ok(Opcode::JS(Test::try_from(t)?, Dest::from(b))),
+                _ => Err("Unknown"),
+            }
+        } else if s.is_empty() {
+            Err("Empty rule")
+        } else {
+            Ok(Opcode::J(Dest::from(s)))
+        }
+    }
+}
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let mut workflow: HashMap<String, Vec<Opcode>> = HashMap::new();
+    let mut parts: Vec<Part> = Vec::new();
+    let mut section = 0;
+    parse::sections(input, |s| {
+        section += 1;
+        for l in s {
+            match section {
+                1 => {
+                    let (name, rules) = l.block("{", "}")?;
+                    workflow.insert(
+                        name.text.to_string(),
+                        rules
+                            .list(',')
+                            .iter()
+                            .map(|r| Opcode::try_from(r.text).map_err(|e| r.error(e)))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    );
+                }
+                2 => parts.push(Part::try_from(l.text).map_err(|e| l.error(e))?),
+                _ => return Err(l.error("unexpected section")),
+            }
+        }
+        Ok(())
+    })?;
+    Ok((parts, workflow))
+}
+
+fn run(p: &Part, w: &HashMap<String, Vec<Opcode>>) -> bool {
+    let mut rule = w.get("in").unwrap();
+    loop {
+        for op in rule {
+            match op {
+                Opcode::JX(test, dest) => {
+                    if test.run(p.x) {
+                        match dest {
+                            Dest::Accept => return true,
+                            Dest::Reject => return false,
+                            Dest::Rule(r) => {
+                                rule = w.get(r).unwrap();
+                                break;
+                            }
+                        }
+                    }
+                }
+                Opcode::JM(test, dest) => {
+                    if test.run(p.m) {
+                        match dest {
+                            Dest::Accept => return true,
+                            Dest::Reject => return false,
+                            Dest::Rule(r) => {
+                                rule = w.get(r).unwrap();
+                                break;
+                            }
+                        }
+                    }
+                }
+                Opcode::JA(test, dest) => {
+                    if test.run(p.a) {
+                        match dest {
+                            Dest::Accept => return true,
+                            Dest::Reject => return false,
+                            Dest::Rule(r) => {
+                                rule = w.get(r).unwrap();
+                                break;
+                            }
+                        }
+                    }
+                }
+                Opcode::JS(test, dest) => {
+                    if test.run(p.s) {
+                        match dest {
+                            Dest::Accept => return true,
+                            Dest::Reject => return false,
+                            Dest::Rule(r) => {
+                                rule = w.get(r).unwrap();
+                                break;
+                            }
+                        }
+                    }
+                }
+                Opcode::J(dest) => match dest {
+                    Dest::Accept => return true,
+                    Dest::Reject => return false,
+                    Dest::Rule(r) => {
+                        rule = w.get(r).unwrap();
+                        break;
+                    }
+                },
+            }
+        }
+    }
+    false
+}
+
+#[derive(Debug, Clone)]
+struct Partition {
+    x: Range<i32>,
+    m: Range<i32>,
+    a: Range<i32>,
+    s: Range<i32>,
+}
+
+impl Partition {
+    fn count(&self) -> usize {
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+    }
+    fn splitx(&self, n: i32) -> (Partition, Partition) {
+        assert!(self.x.contains(&n));
+        let mut p1 = self.clone();
+        let mut p2 = self.clone();
+        p1.x = self.x.start..n;
+        p2.x = n..self.x.end;
+        (p1, p2)
+    }
+    fn splitm(&self, n: i32) -> (Partition, Partition) {
+        assert!(self.m.contains(&n));
+        let mut p1 = self.clone();
+        let mut p2 = self.clone();
+        p1.m = self.m.start..n;
+        p2.m = n..self.m.end;
+        (p1, p2)
+    }
+    fn splita(&self, n: i32) -> (Partition, Partition) {
+        assert!(self.a.contains(&n));
+        let mut p1 = self.clone();
+        let mut p2 = self.clone();
+        p1.a = self.a.start..n;
+        p2.a = n..self.a.end;
+        (p1, p2)
+    }
+    fn splits(&self, n: i32) -> (Partition, Partition) {
+        assert!(self.s.contains(&n));
+        let mut p1 = self.clone();
+        let mut p2 = self.clone();
+        p1.s = self.s.start..n;
+        p2.s = n..self.s.end;
+        (p1, p2)
+    }
+}
+
+fn split(input: &Partition, opcodes: &Vec<Opcode>) -> Vec<(Dest, Partition)> {
+    let mut out: Vec<(Dest, Partition)> = Vec::new();
+    let mut p: Partition = input.clone();
+    for op in opcodes {
+        match op {
+            Opcode::JX(Test::Less(n), dest) => {
+                if p.x.contains(n) {
+                    let (p1, p2) = p.splitx(*n);
+                    out.push((dest.clone(), p1));
+                    p = p2;
+                }
+            }
+            Opcode::JX(Test::Greater(n), dest) => {
+                let n = n + 1; // For > we split at n+1
+                if p.x.contains(&n) {
+                    let (p1, p2) = p.splitx(n);
+                    out.push((dest.clone(), p2));
+                    p = p1;
+                }
+            }
+            Opcode::JM(Test::Less(n), dest) => {
+                if p.m.contains(n) {
+                    let (p1, p2) = p.splitm(*n);
+                    out.push((dest.clone(), p1));
+                    p = p2;
+                }
+            }
+            Opcode::JM(Test::Greater(n), dest) => {
+                let n = n + 1; // For > we split at n+1
+                if p.m.contains(&n) {
+                    let (p1, p2) = p.splitm(n);
+                    out.push((dest.clone(), p2));
+                    p = p1;
+                }
+            }
+            Opcode::JA(Test::Less(n), dest) => {
+                if p.a.contains(n) {
+                    let (p1, p2) = p.splita(*n);
+                    out.push((dest.clone(), p1));
+                    p = p2;
+                }
+            }
+            Opcode::JA(Test::Greater(n), dest) => {
+                let n = n + 1; // For > we split at n+1
+                if p.a.contains(&n) {
+                    let (p1, p2) = p.splita(n);
+                    out.push((dest.clone(), p2));
+                    p = p1;
+                }
+            }
+            Opcode::JS(Test::Less(n), dest) => {
+                if p.s.contains(n) {
+                    let (p1, p2) = p.splits(*n);
+                    out.push((dest.clone(), p1));
+                    p = p2;
+                }
+            }
+            Opcode::JS(Test::Greater(n), dest) => {
+                let n = n + 1; // For > we split at n+1
+                if p.s.contains(&n) {
+                    let (p1, p2) = p.splits(n);
+                    out.push((dest.clone(), p2));
+                    p = p1;
+                }
+            }
+            Opcode::J(dest) => {
+                out.push((dest.clone(), p.clone()));
+                break;
+            }
+        }
+    }
+    out
+}
+
+fn part1((parts, workflow): &In) -> Out {
+    parts
+        .iter()
+        .filter_map(|p| {
+            if run(p, workflow) {
+                Some((p.x + p.m + p.a + p.s) as usize)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part2((_, workflow): &In) -> Out {
+    let start = Partition {
+        x: 1..4001,
+        m: 1..4001,
+        a: 1..4001,
+        s: 1..4001,
+    };
+    let mut parts = vec![(Dest::Rule("in".to_string()), start)];
+    while parts.iter().any(|(d, _)| matches!(d, Dest::Rule(_))) {
+        let mut next: Vec<(Dest, Partition)> = vec![];
+        for (d, p) in &parts {
+            match d {
+                Dest::Accept | Dest::Reject => next.push((d.clone(), p.clone())),
+                Dest::Rule(r) => split(p, workflow.get(r).unwrap())
+                    .into_iter()
+                    .for_each(|s| next.push(s)),
+            }
+        }
+        parts = next;
+    }
+    parts
+        .iter()
+        .filter(|&(d, _)| matches!(d, Dest::Accept))
+        .map(|(_, p)| p.count())
+        .sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = r"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
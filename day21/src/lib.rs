@@ -0,0 +1,148 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+#[cfg(test)]
+use util::automaton::Automaton;
+use util::grid::Grid;
+use util::point::*;
+use util::runner::Solution;
+
+#[derive(Debug, Clone)]
+struct T();
+
+type In = (Grid<char>, Point);
+type Out = usize;
+const PART1_RESULT: Out = 16;
+const PART2_RESULT: Out = 6536;
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let mut map = Grid::from_reader(input, |b| match b {
+        b'.' | b'#' | b'S' => Ok(b as char),
+        _ => Err("invalid cell"),
+    })?;
+    let start = *map
+        .find(&'S')
+        .first()
+        .ok_or_else(|| Error::new(InvalidData, "missing start"))?;
+    map.set(&start, '.');
+    Ok((map, start))
+}
+
+// Plots reachable in exactly n steps on the infinitely repeated map for
+// each n in limits - one search out to the largest limit then count the
+// plots within n steps with the same parity as n
+fn reachable_counts<const N: usize>((map, start): &In, limits: [usize; N]) -> [usize; N] {
+    let max = limits.iter().copied().max().unwrap_or(0) as u32;
+    let dist = map
+        .tiled()
+        .distance_map_limit(&[*start], |&c| c == '.', max);
+    limits.map(|n| {
+        dist.data
+            .values()
+            .filter(|&&d| d as usize <= n && d as usize % 2 == n % 2)
+            .count()
+    })
+}
+
+fn reachable(input: &In, steps: usize) -> usize {
+    reachable_counts(input, [steps])[0]
+}
+
+fn part1((map, start): &In, count: usize) -> Out {
+    map.reachable_in(&[*start], |&c| c == '.', count as u32)
+}
+
+// Cross-check for part1 (only used by the tests) - step the set of reached
+// plots directly, a plot is reached if one of its neighbours was reached on
+// the previous step
+#[cfg(test)]
+fn part1_automaton((map, start): &In, count: usize) -> Out {
+    let mut map = map.clone();
+    map.set(start, 'O');
+    let mut a = Automaton::new(map, Neighbourhood::VonNeumann);
+    a.run(count, |_, &c, n| match c {
+        '#' => '#',
+        _ if n.any(|&c| c == 'O') => 'O',
+        _ => '.',
+    });
+    a.grid().find(&'O').len()
+}
+
+// The input has a clear row and column through the start (which is in the
+// centre of a square map) so the reachable count grows quadratically with
+// each extra map width - fit a quadratic to the first three widths
+fn part2(input: &In, steps: usize) -> Out {
+    let size = input.0.size.dx as usize;
+    let (n, r) = (steps / size, steps % size);
+    let [a0, a1, a2] = reachable_counts(input, [r, r + size, r + 2 * size]);
+    a0 + n * (a1 - a0) + n * n.saturating_sub(1) / 2 * (a2 + a0 - 2 * a1)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input, 64)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input, 26501365))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input, 6), PART1_RESULT);
+    assert_eq!(part1_automaton(&input, 6), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(reachable(&input, 6), PART1_RESULT);
+    assert_eq!(reachable(&input, 10), 50);
+    assert_eq!(reachable(&input, 50), 1594);
+    assert_eq!(reachable(&input, 100), PART2_RESULT);
+}
+
+// part2 relies on a clear row and column through the start (which the
+// example doesn't have) so check it against the full count on a cleared map
+#[test]
+fn test_part2_extrapolation() {
+    let (mut map, start) = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    for i in 0..map.size.dx {
+        map.set(&Point::new(i, start.y), '.').unwrap();
+        map.set(&Point::new(start.x, i), '.').unwrap();
+    }
+    let input = (map, start);
+    for steps in [5 + 11 * 2, 5 + 11 * 5, 2 + 11 * 4, 10 + 11 * 6] {
+        assert_eq!(part2(&input, steps), reachable(&input, steps));
+    }
+}
+
+#[cfg(test)]
+const TESTDATA: &str = r"
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
@@ -0,0 +1,232 @@
+#![allow(unused)]
+
+use rayon::prelude::*;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::parse;
+use util::runner::Solution;
+
+type In = Vec<Brick>;
+type Out = usize;
+const PART1_RESULT: Out = 5;
+const PART2_RESULT: Out = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct P3 {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl P3 {
+    fn drop(&self) -> P3 {
+        P3 {
+            x: self.x,
+            y: self.y,
+            z: self.z - 1,
+        }
+    }
+}
+
+fn p3(x: i32, y: i32, z: i32) -> P3 {
+    P3 { x, y, z }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Brick {
+    blocks: Vec<P3>,
+    bottom: Vec<P3>,
+    minz: i32,
+}
+
+impl Brick {
+    fn new(p1: P3, p2: P3) -> Brick {
+        let mut blocks: Vec<P3> = vec![];
+        let mut bottom: Vec<P3> = vec![];
+        let minz = p1.z.min(p2.z);
+        for x in p1.x.min(p2.x)..=p1.x.max(p2.x) {
+            for y in p1.y.min(p2.y)..=p1.y.max(p2.y) {
+                for z in p1.z.min(p2.z)..=p1.z.max(p2.z) {
+                    blocks.push(P3 { x, y, z });
+                    if z == minz {
+                        bottom.push(P3 { x, y, z });
+                    }
+                }
+            }
+        }
+        Brick {
+            blocks,
+            bottom,
+            minz,
+        }
+    }
+    fn drop(&self) -> Brick {
+        Brick {
+            blocks: self.blocks.iter().map(|b| b.drop()).collect::<Vec<_>>(),
+            bottom: self.bottom.iter().map(|b| b.drop()).collect::<Vec<_>>(),
+            minz: self.minz - 1,
+        }
+    }
+}
+
+// Order bricks by lowest z value
+impl PartialOrd for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.minz.cmp(&self.minz)
+    }
+}
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    parse::lines(input, |l| {
+        let (a, b) = l.split_once("~")?;
+        match (
+            a.numbers::<i32>()?.as_slice(),
+            b.numbers::<i32>()?.as_slice(),
+        ) {
+            ([x1, y1, z1], [x2, y2, z2]) => Ok(Brick::new(p3(*x1, *y1, *z1), p3(*x2, *y2, *z2))),
+            _ => Err(l.error("expected x,y,z~x,y,z")),
+        }
+    })
+}
+
+fn drop(brick: &Brick, occupied: &HashSet<P3>) -> Option<(Brick, HashSet<P3>)> {
+    let mut new = brick.clone();
+    let mut dropped = false;
+    while new.minz > 1 && !new.bottom.iter().any(|p| occupied.contains(&p.drop())) {
+        dropped = true;
+        new = new.drop();
+    }
+    if dropped {
+        let mut occupied = occupied.clone();
+        brick.blocks.iter().for_each(|b| {
+            occupied.remove(b);
+        });
+        new.blocks.iter().for_each(|b| {
+            occupied.insert(*b);
+        });
+        Some((new, occupied))
+    } else {
+        None
+    }
+}
+
+fn part1(input: &In) -> Out {
+    // Drop blocks in height order
+    let mut moved: Vec<Brick> = vec![];
+    let mut blocks: BinaryHeap<Brick> = BinaryHeap::from_iter(input.iter().cloned());
+    let mut occupied: HashSet<P3> =
+        HashSet::from_iter(input.iter().flat_map(|b| b.blocks.iter().cloned()));
+    while let Some(b) = blocks.pop() {
+        if let Some((b2, o2)) = drop(&b, &occupied) {
+            moved.push(b2);
+            occupied = o2;
+        } else {
+            moved.push(b);
+        }
+    }
+    // Disintegrate bricks
+    moved
+        .par_iter()
+        .map(|b| {
+            let mut occupied = occupied.clone();
+            b.blocks.iter().for_each(|b| {
+                occupied.remove(b);
+            });
+            moved
+                .iter()
+                .filter(|&b2| b != b2)
+                .all(|b2| drop(b2, &occupied).is_none())
+        })
+        .filter(|b| *b)
+        .count()
+}
+
+fn part2(input: &In) -> Out {
+    // Drop blocks in height order
+    let mut moved: Vec<Brick> = vec![];
+    let mut blocks: BinaryHeap<Brick> = BinaryHeap::from_iter(input.iter().cloned());
+    let mut occupied: HashSet<P3> =
+        HashSet::from_iter(input.iter().flat_map(|b| b.blocks.iter().cloned()));
+    while let Some(b) = blocks.pop() {
+        if let Some((b2, o2)) = drop(&b, &occupied) {
+            moved.push(b2);
+            occupied = o2;
+        } else {
+            moved.push(b);
+        }
+    }
+    // Disintegrate bricks & count number falling
+    moved
+        .par_iter()
+        .flat_map(|b| {
+            let mut occupied = occupied.clone();
+            b.blocks.iter().for_each(|b| {
+                occupied.remove(b);
+            });
+            moved
+                .iter()
+                .filter(|&b2| b != b2)
+                .map(|b2| {
+                    if let Some((_, o2)) = drop(b2, &occupied) {
+                        occupied = o2;
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[test]
+fn test_part2() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part2(&input), PART2_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
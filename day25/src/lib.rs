@@ -0,0 +1,143 @@
+#![allow(unused)]
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind::InvalidData;
+use util::combinations::combinations;
+use util::parse;
+use util::runner::Solution;
+use util::simplegraph::Graph;
+
+#[derive(Debug, Clone)]
+struct T();
+
+type In = Vec<(String, String, u32)>;
+type Out = usize;
+const PART1_RESULT: Out = 54;
+// Testdata: ("hfx", "pzl"), ("bvb", "cmg"), ("nvd", "jqt")
+// Puzzle:   ("xsl", "tpb"), ("qpg", "lrd"), ("bmx", "zlv")
+
+fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
+    let data = parse::key_lists(input, ":")?
+        .into_iter()
+        .flat_map(|(a, b)| b.into_iter().map(move |c| (a.clone(), c, 1_u32)))
+        .collect::<Vec<_>>();
+    Ok(data)
+}
+
+fn reachable(g: &Graph<String>, start: &str) -> HashSet<String> {
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut q: Vec<String> = vec![start.to_string()];
+    while let Some(v) = q.pop() {
+        if !visited.contains(&v) {
+            visited.insert(v.clone());
+            g.edges(&v)
+                .unwrap()
+                .iter()
+                .for_each(|e| q.push(e.key().clone()));
+        }
+    }
+    visited
+}
+
+// Returns sorted list of most visited nodes in 'long' paths
+fn find_most_visited_paths(g: &Graph<String>, nodes: &Vec<&String>) -> Vec<(String, usize)> {
+    // Node instance counter
+    let mut counter: HashMap<String, usize> = HashMap::new();
+    for start in nodes {
+        // Get paths from start node to all other nodes
+        let (costs, from) = g.astar_all(start, |_| 1);
+        // Find longest path
+        let max = costs.iter().fold(0, |acc, (_, &v)| acc.max(v));
+        // Filter costs to only include paths > max - 2
+        // (assume that long paths will be across partition)
+        let long = costs
+            .iter()
+            .filter(|(_, &v)| v > max - 2)
+            .collect::<Vec<_>>();
+        // For each long destination calculate path and
+        // count instances (we assume that partition nodes
+        // will be visited more often)
+        for v in &long {
+            let mut current = v.0.clone();
+            let mut path = [current.clone()];
+            while let Some(prev) = from.get(&current) {
+                *counter.entry(prev.clone()).or_insert(0) += 1;
+                current = prev.clone();
+            }
+        }
+    }
+    // Sort output
+    let mut out = counter.into_iter().collect::<Vec<_>>();
+    out.sort_by_key(|(k, v)| *v);
+    out.reverse();
+    out
+}
+
+fn part1(input: &In) -> Out {
+    let g = Graph::new_from_bidirectional_edges(input.clone());
+    // Sort vertices so that the sample is stable across runs
+    let mut vertices = g.vertices().collect::<Vec<_>>();
+    vertices.sort();
+    // Get most visited paths from sample of vertices - 20 seems to work
+    let most_visited =
+        find_most_visited_paths(&g, &vertices.into_iter().take(20).collect::<Vec<_>>());
+    let partition = most_visited
+        .into_iter()
+        .take(6)
+        .map(|(n, _)| n)
+        .collect::<Vec<_>>();
+    let partitioned = input
+        .iter()
+        .filter(|(a, b, _)| !(partition.contains(a) && partition.contains(b)))
+        .cloned()
+        .collect::<Vec<_>>();
+    // Check reachable nodes from each partition
+    let g = Graph::new_from_bidirectional_edges(partitioned);
+    let p1 = reachable(&g, &partition[0]);
+    // Find node outside p1 partition
+    let n2 = partition.iter().find(|&n| !p1.contains(n)).unwrap();
+    let p2 = reachable(&g, n2);
+    p1.len() * p2.len()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+}
+
+#[test]
+fn test_part1() {
+    let input = parse_input(&mut TESTDATA.trim_matches('\n').as_bytes()).unwrap();
+    assert_eq!(part1(&input), PART1_RESULT);
+}
+
+#[cfg(test)]
+const TESTDATA: &str = r"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
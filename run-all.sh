@@ -1,18 +1,3 @@
 #!/bin/sh
 
-for d in day*
-do
-    cd $d
-    cargo build --release
-    cd ..
-done
-
-time ( 
-    for d in day*
-    do
-        echo '===' $d
-        cd $d
-        ./target/release/$d
-        cd ..
-    done
-)
+cargo build --release && ./target/release/aoc run all
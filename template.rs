@@ -8,7 +8,8 @@ use std::io::prelude::*;
 use std::io::BufReader;
 use std::io::Error;
 use std::io::ErrorKind::InvalidData;
-use std::time::Instant;
+use util::parse;
+use util::runner::Solution;
 
 #[derive(Debug, Clone)]
 struct T();
@@ -19,10 +20,7 @@ const PART1_RESULT: Out = 0;
 const PART2_RESULT: Out = 0;
 
 fn parse_input(input: &mut impl Read) -> std::io::Result<In> {
-    let data = BufReader::new(input)
-        .lines()
-        .map(|l| l.unwrap().bytes().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+    let data = parse::lines(input, |l| Ok(l.text.bytes().collect::<Vec<_>>()))?;
     Ok(())
 }
 
@@ -34,22 +32,20 @@ fn part2(input: &In) -> Out {
     PART2_RESULT
 }
 
-fn main() -> std::io::Result<()> {
-    let mut f = File::open("input.txt")?;
-    let input = parse_input(&mut f)?;
-    let p1 = Instant::now();
-    println!(
-        "Part1: {:?} ({}s)",
-        part1(&input),
-        p1.elapsed().as_secs_f32()
-    );
-    let p2 = Instant::now();
-    println!(
-        "Part2: {:?} ({}s)",
-        part2(&input),
-        p2.elapsed().as_secs_f32()
-    );
-    Ok(())
+pub struct Day;
+
+impl Solution for Day {
+    type Input = In;
+    type Output = Out;
+    fn parse(input: &mut impl Read) -> std::io::Result<In> {
+        parse_input(input)
+    }
+    fn part1(input: &In) -> Out {
+        part1(input)
+    }
+    fn part2(input: &In) -> Option<Out> {
+        Some(part2(input))
+    }
 }
 
 #[test]
@@ -0,0 +1,217 @@
+use crate::grid::Grid;
+use crate::point::{Neighbourhood, Offset, Point};
+use std::fmt::Display;
+
+// Cellular automaton over a grid - each generation every cell is replaced by
+// rule(point, cell, neighbours) computed from the previous generation. The
+// next generation is written into a second buffer which is then swapped in
+// so stepping doesn't allocate
+pub struct Automaton<T> {
+    grid: Grid<T>,
+    buffer: Vec<T>,
+    offsets: Vec<Offset>,
+    generation: usize,
+}
+
+// run_until_stable gave up with the grid still changing after this many
+// generations (eg. an oscillator)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unstable(pub usize);
+
+impl Display for Unstable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "still changing after {} generations", self.0)
+    }
+}
+
+impl std::error::Error for Unstable {}
+
+// A cell's neighbours as seen by a rule - cells outside the grid are missing
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+    offsets: &'a [Offset],
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    // Any cell relative to this one (not just those in the neighbourhood)
+    pub fn get(&self, o: Offset) -> Option<&'a T> {
+        self.grid.get(&(self.point + o))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.offsets
+            .iter()
+            .filter_map(|&o| self.get(o).map(|v| (self.point + o, v)))
+    }
+    pub fn count(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|(_, v)| pred(v)).count()
+    }
+    pub fn any(&self, pred: impl Fn(&T) -> bool) -> bool {
+        self.iter().any(|(_, v)| pred(v))
+    }
+}
+
+impl<T: Clone> Automaton<T> {
+    pub fn new(grid: Grid<T>, neighbourhood: Neighbourhood) -> Self {
+        Automaton {
+            buffer: grid.data.clone(),
+            grid,
+            offsets: neighbourhood.offsets(),
+            generation: 0,
+        }
+    }
+}
+
+impl<T> Automaton<T> {
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+    // Number of steps run so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    pub fn step(&mut self, rule: impl Fn(Point, &T, &Neighbours<T>) -> T) {
+        for (i, next) in self.buffer.iter_mut().enumerate() {
+            *next = apply(&self.grid, &self.offsets, i, &rule);
+        }
+        self.swap();
+    }
+    pub fn run(&mut self, n: usize, rule: impl Fn(Point, &T, &Neighbours<T>) -> T) {
+        for _ in 0..n {
+            self.step(&rule);
+        }
+    }
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.grid.data, &mut self.buffer);
+        self.generation += 1;
+    }
+}
+
+impl<T: PartialEq> Automaton<T> {
+    // Step until a generation is the same as the one before (running at
+    // most max generations), returning the number of generations which
+    // changed something
+    pub fn run_until_stable(
+        &mut self,
+        max: usize,
+        rule: impl Fn(Point, &T, &Neighbours<T>) -> T,
+    ) -> Result<usize, Unstable> {
+        for changed in 0..max {
+            self.step(&rule);
+            if self.grid.data == self.buffer {
+                return Ok(changed);
+            }
+        }
+        Err(Unstable(max))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> Automaton<T> {
+    // As step with the rows computed in parallel
+    pub fn par_step(&mut self, rule: impl Fn(Point, &T, &Neighbours<T>) -> T + Sync) {
+        use rayon::prelude::*;
+        let width = (self.grid.size.dx as usize).max(1);
+        let (grid, offsets) = (&self.grid, &self.offsets);
+        self.buffer
+            .par_chunks_mut(width)
+            .enumerate()
+            .for_each(|(y, row)| {
+                for (x, next) in row.iter_mut().enumerate() {
+                    *next = apply(grid, offsets, y * width + x, &rule);
+                }
+            });
+        self.swap();
+    }
+    pub fn par_run(&mut self, n: usize, rule: impl Fn(Point, &T, &Neighbours<T>) -> T + Sync) {
+        for _ in 0..n {
+            self.par_step(&rule);
+        }
+    }
+}
+
+// Next value of cell i
+fn apply<T>(
+    grid: &Grid<T>,
+    offsets: &[Offset],
+    i: usize,
+    rule: impl Fn(Point, &T, &Neighbours<T>) -> T,
+) -> T {
+    let point = grid.index_to_point(i);
+    let neighbours = Neighbours {
+        grid,
+        point,
+        offsets,
+    };
+    rule(point, &grid.data[i], &neighbours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::char_grid;
+
+    fn life(_: Point, &c: &char, n: &Neighbours<char>) -> char {
+        match (c, n.count(|&c| c == '#')) {
+            (_, 3) | ('#', 2) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_automaton() {
+        let blinker = ".....\n..#..\n..#..\n..#..\n.....\n";
+        let mut a = Automaton::new(char_grid(blinker), Neighbourhood::Moore);
+        a.step(life);
+        assert_eq!(a.grid().to_string(), ".....\n.....\n.###.\n.....\n.....\n");
+        a.run(3, life);
+        assert_eq!(a.generation(), 4);
+        assert_eq!(a.into_grid().to_string(), blinker);
+        // Block is a still life
+        let mut a = Automaton::new(char_grid("....\n.##.\n.##.\n....\n"), Neighbourhood::Moore);
+        assert_eq!(a.run_until_stable(10, life), Ok(0));
+        assert_eq!(a.generation(), 1);
+        // Blinker never settles
+        let mut a = Automaton::new(char_grid(blinker), Neighbourhood::Moore);
+        let e = a.run_until_stable(10, life);
+        assert_eq!(e, Err(Unstable(10)));
+        assert_eq!(
+            e.unwrap_err().to_string(),
+            "still changing after 10 generations"
+        );
+        assert_eq!(a.generation(), 10);
+    }
+
+    #[test]
+    fn test_automaton_neighbours() {
+        // Spread along the open cells (VonNeumann so not diagonally)
+        let g = char_grid("O.#\n.#.\n..#\n");
+        let mut a = Automaton::new(g, Neighbourhood::VonNeumann);
+        let steps = a.run_until_stable(10, |_, &c, n| match c {
+            '.' if n.any(|&c| c == 'O') => 'O',
+            c => c,
+        });
+        assert_eq!(steps, Ok(3));
+        assert_eq!(a.grid().to_string(), "OO#\nO#.\nOO#\n");
+        // get can look beyond the neighbourhood
+        let mut a = Automaton::new(char_grid("abc\n"), Neighbourhood::VonNeumann);
+        a.step(|_, _, n| *n.get(Offset::new(2, 0)).unwrap_or(&'-'));
+        assert_eq!(a.grid().to_string(), "c--\n");
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_automaton_parallel() {
+        let glider = ".#....\n..#...\n###...\n......\n......\n......\n";
+        let mut a = Automaton::new(char_grid(glider), Neighbourhood::Moore);
+        let mut b = Automaton::new(char_grid(glider), Neighbourhood::Moore);
+        a.run(4, life);
+        b.par_run(4, life);
+        assert_eq!(a.grid().data, b.grid().data);
+        assert_eq!(b.grid().find(&'#').len(), 5);
+        assert_eq!(b.grid().get(&Point::new(3, 3)), Some(&'#'));
+    }
+}
@@ -18,7 +18,7 @@ pub fn combinations<T: Clone>(v: &Vec<T>, n: usize) -> Vec<Vec<T>> {
 }
 */
 
-pub fn combinations<T: Clone>(v: &Vec<T>, n: usize) -> Vec<Vec<T>> {
+pub fn combinations<T: Clone>(v: &[T], n: usize) -> Vec<Vec<T>> {
     if n == 0 {
         return vec![vec![]];
     }
@@ -34,8 +34,8 @@ pub fn combinations<T: Clone>(v: &Vec<T>, n: usize) -> Vec<Vec<T>> {
         if count == n {
             result.push(current);
         } else {
-            for j in i..v.len() {
-                current.push(v[j].clone());
+            for (j, t) in v.iter().enumerate().skip(i) {
+                current.push(t.clone());
                 stack.push((j + 1, count + 1, current.clone()));
                 current.pop();
             }
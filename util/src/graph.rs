@@ -54,6 +54,15 @@ pub struct Graph<I, D>(HashMap<I, Vertex<I, D>>)
 where
     I: Clone + Eq + Hash;
 
+impl<I, D> Default for Graph<I, D>
+where
+    I: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<I, D> Graph<I, D>
 where
     I: Clone + Eq + Hash,
@@ -9,7 +9,7 @@ struct OrdF32(f32);
 
 impl PartialEq for OrdF32 {
     fn eq(&self, other: &Self) -> bool {
-        (self.0 - other.0).abs() < std::f32::EPSILON
+        (self.0 - other.0).abs() < f32::EPSILON
     }
 }
 
@@ -17,17 +17,15 @@ impl Eq for OrdF32 {}
 
 impl PartialOrd for OrdF32 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(
-            self.0
-                .partial_cmp(&other.0)
-                .unwrap_or(std::cmp::Ordering::Equal),
-        )
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for OrdF32 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap_or(std::cmp::Ordering::Equal)
+        self.0
+            .partial_cmp(&other.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
     }
 }
 
@@ -41,11 +39,7 @@ where
     I: Eq,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.1.partial_cmp(&other.1).map(|o| match o {
-            Ordering::Less => Ordering::Greater,
-            Ordering::Equal => Ordering::Equal,
-            Ordering::Greater => Ordering::Less,
-        })
+        Some(self.cmp(other))
     }
 }
 impl<I> Ord for V<I>
@@ -72,11 +66,11 @@ where
         let mut open: BinaryHeap<V<I>> = BinaryHeap::new();
         let mut from: HashMap<I, I> = HashMap::new();
         let mut score: HashMap<I, f32> = HashMap::new();
-        open.push(V(start.clone(), OrdF32(h(&start))));
+        open.push(V(start.clone(), OrdF32(h(start))));
         score.insert(start.clone(), 0.0);
         while let Some(current) = open.pop() {
             if current.0 == *target {
-                if let Some(cost) = score.get(&target) {
+                if let Some(cost) = score.get(target) {
                     let mut current = &current.0;
                     let mut path = vec![current.clone()];
                     while let Some(prev) = from.get(current) {
@@ -84,7 +78,7 @@ where
                         current = prev;
                     }
                     path.reverse();
-                    return Some((cost.clone(), path));
+                    return Some((*cost, path));
                 } else {
                     return None;
                 }
@@ -92,10 +86,10 @@ where
             if let Some(v) = self.get(&current.0) {
                 for (n, d) in &v.edges {
                     let tentative = score[&current.0] + d;
-                    if tentative < *score.get(&n).unwrap_or(&f32::INFINITY) {
+                    if tentative < *score.get(n).unwrap_or(&f32::INFINITY) {
                         from.insert(n.clone(), current.0.clone());
                         score.insert(n.clone(), tentative);
-                        open.push(V(n.clone(), OrdF32(tentative + h(&n))));
+                        open.push(V(n.clone(), OrdF32(tentative + h(n))));
                     }
                 }
             }
@@ -156,11 +150,11 @@ mod tests {
         g
     }
 
-    fn adj(a: &Vec<Vec<f32>>, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    fn adj(a: &[Vec<f32>], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
         let (x_max, y_max) = (a[0].len() as i32, a.len() as i32);
         let (x, y) = (x as i32, y as i32);
         let mut out = vec![];
-        for (dx, dy) in vec![(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
             if x + dx >= 0 && x + dx < x_max && y + dy >= 0 && y + dy < y_max {
                 out.push(((x + dx) as usize, (y + dy) as usize));
             }
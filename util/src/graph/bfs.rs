@@ -30,7 +30,7 @@ where
                     return Some(path);
                 }
                 for (e, _) in &v.edges {
-                    if !explored.contains(&e) {
+                    if !explored.contains(e) {
                         explored.insert(e.clone());
                         parent.insert(e.clone(), i.clone());
                         q.push_back(e.clone());
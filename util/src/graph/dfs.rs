@@ -38,9 +38,9 @@ impl<I, D> Graph<I, D>
 where
     I: Clone + Eq + Hash,
 {
-    pub fn dfs_iter(&self, root: &I) -> DfsIter<I, D> {
+    pub fn dfs_iter(&self, root: &I) -> DfsIter<'_, I, D> {
         DfsIter {
-            graph: &self,
+            graph: self,
             discovered: HashSet::new(),
             stack: vec![root.clone()],
         }
@@ -51,7 +51,7 @@ where
         F: FnMut(&Vertex<I, D>),
     {
         let mut discovered: HashSet<I> = HashSet::new();
-        Self::dfs_r(&self, &mut discovered, root, f);
+        Self::dfs_r(self, &mut discovered, root, f);
     }
 
     fn dfs_r<F>(graph: &Graph<I, D>, discovered: &mut HashSet<I>, i: &I, f: &mut F)
@@ -59,7 +59,7 @@ where
         F: FnMut(&Vertex<I, D>),
     {
         discovered.insert(i.clone());
-        if let Some(v) = graph.get(&i) {
+        if let Some(v) = graph.get(i) {
             f(v);
             for (e, _) in &v.edges {
                 if !discovered.contains(e) {
@@ -94,7 +94,7 @@ mod tests {
         g.dfs(&"A", &mut f);
         assert_eq!(
             out,
-            vec!["A", "B", "D", "F", "E", "C", "G"]
+            ["A", "B", "D", "F", "E", "C", "G"]
                 .iter()
                 .map(|s| s.to_string())
                 .collect::<Vec<_>>()
@@ -17,7 +17,7 @@ where
                     cost.insert(
                         (&u.key, &v.key),
                         match u.edges.iter().find(|&e| e.0 == v.key) {
-                            Some(&(_, c)) => c as f32,
+                            Some(&(_, c)) => c,
                             None => f32::INFINITY,
                         },
                     );
@@ -62,7 +62,7 @@ mod tests {
                 assert_eq!(
                     costs[&(u, v)],
                     match g.astar(u, v, |_| 1.0) {
-                        Some((c, _)) => c as f32,
+                        Some((c, _)) => c,
                         None => f32::INFINITY,
                     }
                 );
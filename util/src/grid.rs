@@ -1,7 +1,38 @@
-use crate::point::{Offset, Point, ADJACENT};
+// GridView, TiledGrid and Window only borrow the grid so are always Copy,
+// but derive would add a T: Clone bound - defined before the submodules so
+// they can use it
+macro_rules! borrowed_copy {
+    ($view:ident) => {
+        impl<T> Clone for $view<'_, T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for $view<'_, T> {}
+    };
+}
+
+pub mod bits;
+pub mod diff;
+pub mod distance;
+pub mod fill;
+pub mod image;
+pub mod iter;
+pub mod regions;
+pub mod render;
+pub mod runs;
+pub mod scale;
+pub mod settle;
+pub mod tiled;
+pub mod transform;
+pub mod window;
+
+use crate::parse::{ParseError, Span};
+use crate::point::{Offset, Point, ADJACENT, ADJACENT8, UP};
 use std::cmp::{max, min};
-use std::collections::HashSet;
 use std::fmt::Display;
+use std::io::Read;
 
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
@@ -33,6 +64,7 @@ impl<T> Grid<T> {
             None
         }
     }
+    #[allow(clippy::result_unit_err)]
     pub fn set(&mut self, p: &Point, new: T) -> Result<(), ()> {
         if let Some(old) = self.get_mut(p) {
             *old = new;
@@ -44,17 +76,138 @@ impl<T> Grid<T> {
     pub fn adjacent(&self, p: &Point) -> Vec<Point> {
         self.offset(p, ADJACENT)
     }
-    pub fn offset<O: AsRef<[Offset]>>(&self, p: &Point, offsets: O) -> Vec<Point> {
+    pub fn adjacent8(&self, p: &Point) -> Vec<Point> {
+        self.offset(p, ADJACENT8)
+    }
+    // Accepts an array of offsets (eg. ADJACENT) or a Neighbourhood
+    pub fn offset(&self, p: &Point, offsets: impl IntoIterator<Item = Offset>) -> Vec<Point> {
         offsets
-            .as_ref()
-            .iter()
-            .map(|&o| *p + o)
+            .into_iter()
+            .map(|o| *p + o)
             .filter(|p| self.check_bounds(p))
             .collect()
     }
+    // All points enclosed by a closed loop given as a path of adjacent points
+    // (the first and last points are also joined) in a single scan of the
+    // grid - the same crossing rule as inside
+    pub fn enclosed_cells(&self, loop_points: &[Point]) -> Vec<Point> {
+        const LOOP: u8 = 1;
+        const CROSSING: u8 = 2;
+        let mut marks = Grid::empty(&self.start, &self.end, 0_u8);
+        let n = loop_points.len();
+        for (i, p) in loop_points.iter().enumerate() {
+            let (prev, next) = (loop_points[(i + n - 1) % n], loop_points[(i + 1) % n]);
+            if let Some(m) = marks.get_mut(p) {
+                *m |= LOOP;
+                if prev == *p + UP || next == *p + UP {
+                    *m |= CROSSING;
+                }
+            }
+        }
+        let mut out = vec![];
+        for y in self.start.y..=self.end.y {
+            let mut inside = false;
+            for (p, &m) in marks.row(y) {
+                if m & CROSSING != 0 {
+                    inside = !inside;
+                } else if m & LOOP == 0 && inside {
+                    out.push(p);
+                }
+            }
+        }
+        out
+    }
+    pub fn neighbours_with_values(
+        &self,
+        p: &Point,
+        offsets: impl IntoIterator<Item = Offset>,
+    ) -> Vec<(Point, &T)> {
+        offsets
+            .into_iter()
+            .filter_map(|o| self.get(&(*p + o)).map(|v| (*p + o, v)))
+            .collect()
+    }
     pub fn index_to_point(&self, i: usize) -> Point {
         self.start + Offset::new(i as i64 % self.size.dx, i as i64 / self.size.dx)
     }
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            start: self.start,
+            end: self.end,
+            size: self.size,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+    pub fn map_with_point<U>(&self, f: impl Fn(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            start: self.start,
+            end: self.end,
+            size: self.size,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(i, v)| f(self.index_to_point(i), v))
+                .collect(),
+        }
+    }
+    // Build from lines of bytes (e.g. a parse::sections block) mapping each
+    // through f - rows must all be the same length
+    pub fn from_lines<E: Display>(
+        lines: &[Span],
+        f: impl Fn(u8) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let first = lines.first().copied().unwrap_or(Span::new("", 1));
+        if first.is_empty() {
+            return Err(first.error("empty grid"));
+        }
+        let width = first.text.len();
+        let mut data = Vec::with_capacity(width * lines.len());
+        for l in lines {
+            if l.text.len() != width {
+                return Err(l.error(&format!("expected {} columns", width)));
+            }
+            for (i, b) in l.text.bytes().enumerate() {
+                data.push(f(b).map_err(|e| ParseError {
+                    line: l.line,
+                    column: l.column + i,
+                    text: (b as char).to_string(),
+                    msg: e.to_string(),
+                })?);
+            }
+        }
+        let start = Point::new(0, 0);
+        let end = Point::new(width as i64 - 1, lines.len() as i64 - 1);
+        Ok(Self {
+            start,
+            end,
+            size: (end - start) + Offset::new(1, 1),
+            data,
+        })
+    }
+    // Trailing blank lines are ignored
+    pub fn parse_str<E: Display>(
+        s: &str,
+        f: impl Fn(u8) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Span::new(l, i + 1))
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        Self::from_lines(&lines, f)
+    }
+    pub fn from_reader<E: Display>(
+        input: &mut impl Read,
+        f: impl Fn(u8) -> Result<T, E>,
+    ) -> std::io::Result<Self> {
+        let mut s = String::new();
+        input.read_to_string(&mut s)?;
+        Ok(Self::parse_str(&s, f)?)
+    }
 }
 
 impl<T> Grid<T>
@@ -74,79 +227,35 @@ where
             })
             .collect::<Vec<_>>()
     }
-    pub fn inside(&self, p: &Point, walls: &Vec<T>) -> bool {
-        if !self.check_bounds(p) || walls.contains(self.get(p).unwrap()) {
+    // Point in loop test for a closed loop given as a path of adjacent
+    // points - casts a ray left from p and counts the loop cells which
+    // connect upwards, so corners are handled (eg. F-7 isn't a crossing, F-J
+    // is) even where the loop runs alongside itself
+    pub fn inside(&self, p: &Point, loop_points: &[Point]) -> bool {
+        if !self.check_bounds(p) || loop_points.contains(p) {
             return false;
         }
-        let mut wall = false;
-        let mut n_walls = 0;
-        for x in 0..=p.x {
-            let current = self.get(&Point::new(x, p.y)).unwrap();
-            if walls.contains(current) {
-                wall = true;
-            } else {
-                if wall {
-                    n_walls += 1;
-                    wall = false;
-                }
-            }
-        }
-        let walls_l = n_walls;
-        wall = false;
-        n_walls = 0;
-        for x in p.x..=self.end.x {
-            let current = self.get(&Point::new(x, p.y)).unwrap();
-            if walls.contains(current) {
-                wall = true;
-            } else {
-                if wall {
-                    n_walls += 1;
-                    wall = false;
-                }
-            }
-        }
-        let walls_r = n_walls;
-        println!("{} : {}-{}", p, walls_l, walls_r);
-        false
-    }
-}
-
-impl<T> Grid<T>
-where
-    T: PartialEq + Eq + Clone,
-{
-    pub fn fill(&mut self, start: &Point, wall: &Vec<T>, fill: Option<&T>) -> Vec<Point> {
-        assert!(self.check_bounds(&start));
-        assert!(!wall.contains(self.get(&start).unwrap()));
-        let mut q = vec![start.clone()];
-        let mut visited: HashSet<Point> = HashSet::new();
-        while let Some(p) = q.pop() {
-            if let Some(f) = fill {
-                self.set(&p, f.clone()).unwrap();
-            }
-            visited.insert(p.clone());
-            for adj in self.adjacent(&p) {
-                if !visited.contains(&adj) {
-                    let v = self.get(&adj).unwrap();
-                    if !(wall.contains(&v)) {
-                        q.push(adj);
-                    }
-                }
-            }
-        }
-        visited.into_iter().collect()
+        let n = loop_points.len();
+        let crossings = (0..n)
+            .filter(|&i| {
+                let w = loop_points[i];
+                let (prev, next) = (loop_points[(i + n - 1) % n], loop_points[(i + 1) % n]);
+                w.y == p.y && w.x < p.x && (prev == w + UP || next == w + UP)
+            })
+            .count();
+        crossings % 2 == 1
     }
 }
 
 impl<T> From<Vec<Vec<T>>> for Grid<T> {
     fn from(v: Vec<Vec<T>>) -> Self {
-        assert!(v.len() > 0);
+        assert!(!v.is_empty());
         let start = Point::new(0, 0);
         let end = Point::new(v[0].len() as i64 - 1, v.len() as i64 - 1);
         let size = (end - start) + Offset::new(1, 1);
         let mut data = Vec::with_capacity(((size.dx + 1) * (size.dy + 1)) as usize);
         for row in v {
-            data.extend(row.into_iter());
+            data.extend(row);
         }
         Self {
             start,
@@ -166,20 +275,27 @@ impl<T: Clone> Grid<T> {
             data.push(value.clone());
         }
         Self {
-            start: start.clone(),
-            end: end.clone(),
+            start: *start,
+            end: *end,
             size,
             data,
         }
     }
+    #[allow(clippy::result_unit_err)]
     pub fn draw_line(&mut self, p1: &Point, p2: &Point, v: T) -> Result<(), ()> {
         match (p1.x == p2.x, p1.y == p2.y) {
-            (true, _) => Ok(for y in min(p1.y, p2.y)..=max(p1.y, p2.y) {
-                self.set(&Point::new(p1.x, y), v.clone())?;
-            }),
-            (_, true) => Ok(for x in min(p1.x, p2.x)..=max(p1.x, p2.x) {
-                self.set(&Point::new(x, p1.y), v.clone())?;
-            }),
+            (true, _) => {
+                for y in min(p1.y, p2.y)..=max(p1.y, p2.y) {
+                    self.set(&Point::new(p1.x, y), v.clone())?;
+                }
+                Ok(())
+            }
+            (_, true) => {
+                for x in min(p1.x, p2.x)..=max(p1.x, p2.x) {
+                    self.set(&Point::new(x, p1.y), v.clone())?;
+                }
+                Ok(())
+            }
             _ => Err(()),
         }
     }
@@ -192,15 +308,37 @@ impl<T: Display> Display for Grid<T> {
             for x in 0..self.size.dx {
                 write!(f, "{}", self.data[(x + y * self.size.dx) as usize])?
             }
-            write!(f, "\n")?
+            writeln!(f)?
         }
         Ok(())
     }
 }
 
+// Fixtures shared by the tests here and in the submodules
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::Grid;
+    use crate::point::Point;
+
+    pub fn char_grid(s: &str) -> Grid<char> {
+        Grid::parse_str(s, |b| Ok::<_, &str>(b as char)).unwrap()
+    }
+
+    // 3x2 "ABC/DEF" grid starting at start (so points aren't just indices)
+    pub fn abc_grid(start: Point) -> Grid<char> {
+        let mut g = char_grid("ABC\nDEF\n");
+        g.start = start;
+        g.end = Point::new(start.x + 2, start.y + 1);
+        g
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grid::fill::Edge;
+    use crate::parse;
+    use crate::point::Neighbourhood;
 
     fn make_grid() -> Grid<char> {
         let mut g: Grid<char> = Grid::empty(&Point::new(-2, -2), &Point::new(2, 2), '.');
@@ -218,8 +356,8 @@ mod tests {
     fn test_grid() {
         let mut g: Grid<char> = Grid::empty(&Point::new(-2, -2), &Point::new(2, 2), '.');
         g.set(&Point::new(0, 0), 'X').unwrap();
-        assert_eq!(g.check_bounds(&Point::new(-1, -1)), true);
-        assert_eq!(g.check_bounds(&Point::new(-5, 0)), false);
+        assert!(g.check_bounds(&Point::new(-1, -1)));
+        assert!(!g.check_bounds(&Point::new(-5, 0)));
         assert_eq!(g.get(&Point::new(-3, -3)), None);
         assert_eq!(g.get(&Point::new(0, 0)), Some(&'X'));
         assert_eq!(g.get(&Point::new(-1, -1)), Some(&'.'));
@@ -237,6 +375,71 @@ mod tests {
         assert_eq!(g.get(&Point::new(4, 4)), Some(&'Y'));
     }
 
+    fn digit(b: u8) -> Result<u32, String> {
+        (b as char)
+            .to_digit(10)
+            .ok_or(format!("invalid digit: {}", b as char))
+    }
+
+    #[test]
+    fn test_grid_from_reader() {
+        let g = Grid::from_reader(&mut "123\n456\n".as_bytes(), digit).unwrap();
+        assert_eq!(g.get(&Point::new(2, 1)), Some(&6));
+        assert_eq!(g.end, Point::new(2, 1));
+        let e = Grid::from_reader(&mut "123\n4x6\n".as_bytes(), digit).unwrap_err();
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: invalid digit: x (found \"x\")"
+        );
+        assert!(Grid::from_reader(&mut "".as_bytes(), digit).is_err());
+    }
+
+    #[test]
+    fn test_grid_parse_str() {
+        let g = Grid::parse_str("#.\n.#\n\n", |b| match b {
+            b'#' => Ok(true),
+            b'.' => Ok(false),
+            _ => Err("invalid cell"),
+        })
+        .unwrap();
+        assert_eq!(g.find(&true), vec![Point::new(0, 0), Point::new(1, 1)]);
+        let e = Grid::parse_str("123\n45\n6789\n", digit).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "45"));
+        assert_eq!(e.msg, "expected 3 columns");
+        assert!(Grid::parse_str("12\n\n34\n", digit).is_err());
+    }
+
+    #[test]
+    fn test_grid_from_lines() {
+        let grids = parse::sections(&mut "12\n34\n\n567\n".as_bytes(), |s| {
+            Grid::from_lines(s, digit)
+        })
+        .unwrap();
+        assert_eq!(grids[0].get(&Point::new(1, 1)), Some(&4));
+        assert_eq!(grids[1].end, Point::new(2, 0));
+        let e = parse::sections(&mut "12\n34\n\n567\n8\n".as_bytes(), |s| {
+            Grid::from_lines(s, digit)
+        })
+        .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 5, column 1: expected 3 columns (found \"8\")"
+        );
+    }
+
+    #[test]
+    fn test_grid_map() {
+        let g = make_grid();
+        let lower = g.map(|c| c.to_ascii_lowercase());
+        assert_eq!((lower.start, lower.end), (g.start, g.end));
+        assert_eq!(lower.to_string(), "abcde\nfghij\nklmno\npqrst\nuvwxy\n");
+        let g = g.map_with_point(|p, c| if p.x == p.y { '\\' } else { *c });
+        assert_eq!(g.get(&Point::new(-2, -2)), Some(&'\\'));
+        assert_eq!(g.get(&Point::new(-1, -2)), Some(&'B'));
+        assert_eq!(g.get(&Point::new(2, 2)), Some(&'\\'));
+    }
+
     #[test]
     fn test_grid_make() {
         let g = make_grid();
@@ -262,6 +465,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grid_adjacent8() {
+        let g = make_grid();
+        let values = |points: Vec<Point>| {
+            points
+                .iter()
+                .map(|p| *g.get(p).unwrap())
+                .collect::<String>()
+        };
+        assert_eq!(values(g.adjacent8(&Point::new(0, 0))), "HINSRQLG");
+        assert_eq!(values(g.adjacent8(&Point::new(-2, -2))), "BGF");
+        assert_eq!(
+            values(g.offset(&Point::new(0, 0), Neighbourhood::Moore)),
+            values(g.adjacent8(&Point::new(0, 0)))
+        );
+        assert_eq!(
+            values(g.offset(&Point::new(-2, 0), Neighbourhood::Manhattan(2))),
+            "AFGLMPQU"
+        );
+        assert_eq!(
+            values(g.offset(&Point::new(-2, -2), Neighbourhood::Chebyshev(2))),
+            "BCFGHKLM"
+        );
+    }
+
+    #[test]
+    fn test_grid_neighbours_with_values() {
+        let g = make_grid();
+        assert_eq!(
+            g.neighbours_with_values(&Point::new(2, 2), ADJACENT8),
+            vec![
+                (Point::new(2, 1), &'T'),
+                (Point::new(1, 2), &'X'),
+                (Point::new(1, 1), &'S')
+            ]
+        );
+        assert_eq!(
+            g.neighbours_with_values(&Point::new(0, -2), Neighbourhood::VonNeumann)
+                .iter()
+                .map(|(_, &v)| v)
+                .collect::<String>(),
+            "DHB"
+        );
+    }
+
     #[test]
     fn test_grid_find() {
         let mut g = make_grid();
@@ -297,7 +545,7 @@ mod tests {
     #[test]
     fn test_grid_fill() {
         let mut g = Grid::empty(&Point::new(0, 0), &Point::new(10, 10), '.');
-        for p in vec![
+        for p in [
             [1, 1],
             [3, 1],
             [3, 3],
@@ -319,47 +567,142 @@ mod tests {
             )
             .unwrap();
         }
-        assert_eq!(g.fill(&Point::new(2, 2), &vec!['#'], None).len(), 15);
-        let mut p1 = g.fill(&Point::new(2, 2), &vec!['#'], Some(&'~'));
+        let mut p1 = g
+            .fill(
+                &Point::new(2, 2),
+                |&c| c != '#',
+                Neighbourhood::VonNeumann,
+                Edge::Stop,
+                '~',
+            )
+            .unwrap();
+        assert_eq!(p1.len(), 15);
         let mut p2 = g.find(&'~');
         p1.sort();
         p2.sort();
         assert_eq!(p1, p2);
     }
-    #[test]
-    fn test_grid_inside() {
+    // Loop corners and the cells it encloses
+    const LOOP: [[i64; 2]; 15] = [
+        [1, 1],
+        [3, 1],
+        [3, 3],
+        [7, 3],
+        [7, 5],
+        [9, 5],
+        [9, 9],
+        [7, 9],
+        [7, 7],
+        [5, 7],
+        [5, 8],
+        [3, 8],
+        [3, 6],
+        [1, 6],
+        [1, 1],
+    ];
+    const ENCLOSED: &str = "
+...........
+...........
+..I........
+..I........
+..IIIII....
+..IIIII....
+....IIIII..
+....I...I..
+........I..
+...........
+...........
+";
+
+    fn make_loop() -> (Grid<char>, Vec<Point>) {
         let mut g = Grid::empty(&Point::new(0, 0), &Point::new(10, 10), '.');
-        for p in vec![
-            [1, 1],
-            [3, 1],
-            [3, 3],
-            [7, 3],
-            [7, 5],
-            [9, 5],
-            [9, 9],
-            [7, 9],
-            [7, 7],
-            [5, 7],
-            [5, 8],
-            [3, 8],
-            [3, 6],
-            [1, 6],
-            [1, 1],
-        ]
-        .windows(2)
-        {
-            g.draw_line(
-                &Point::new(p[0][0], p[0][1]),
-                &Point::new(p[1][0], p[1][1]),
-                '#',
-            )
-            .unwrap();
-        }
-        println!("{}", g);
-        for x in 0..=g.end.x {
-            for y in 0..g.end.y {
-                g.inside(&Point::new(x, y), &vec!['#']);
+        let mut path = vec![];
+        for p in LOOP.windows(2) {
+            let (p1, p2) = (Point::new(p[0][0], p[0][1]), Point::new(p[1][0], p[1][1]));
+            g.draw_line(&p1, &p2, '#').unwrap();
+            let d = Offset::new((p2.x - p1.x).signum(), (p2.y - p1.y).signum());
+            let mut p = p1;
+            while p != p2 {
+                path.push(p);
+                p = p + d;
             }
         }
+        (g, path)
+    }
+
+    fn enclosed() -> Vec<Point> {
+        let g = Grid::parse_str(ENCLOSED.trim_start(), |b| Ok::<_, &str>(b as char)).unwrap();
+        g.find(&'I')
+    }
+
+    #[test]
+    fn test_grid_inside() {
+        let (g, path) = make_loop();
+        let inside = g
+            .iter_points()
+            .filter(|(p, _)| g.inside(p, &path))
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        assert_eq!(inside, enclosed());
+        // The loop and points outside the grid are never inside
+        assert!(!g.inside(&Point::new(1, 3), &path));
+        assert!(!g.inside(&Point::new(-1, 3), &path));
+        // Loop running alongside itself - (1,1) is below (1,0) but doesn't
+        // connect to it
+        let u = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (1, 1),
+            (0, 1),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect::<Vec<_>>();
+        let g = Grid::empty(&Point::new(0, 0), &Point::new(3, 3), '.');
+        assert!(!g.inside(&Point::new(3, 1), &u));
+        assert!(!g.inside(&Point::new(0, 2), &u));
+    }
+
+    #[test]
+    fn test_grid_enclosed_cells() {
+        let (g, path) = make_loop();
+        assert_eq!(g.enclosed_cells(&path), enclosed());
+        // Direction of travel doesn't matter
+        let reversed = path.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(g.enclosed_cells(&reversed), enclosed());
+        // Loop running alongside itself
+        let u = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (1, 1),
+            (0, 1),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect::<Vec<_>>();
+        let g = Grid::empty(&Point::new(0, 0), &Point::new(3, 3), '.');
+        assert_eq!(g.enclosed_cells(&u), vec![]);
+        let square = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect::<Vec<_>>();
+        assert_eq!(g.enclosed_cells(&square), vec![Point::new(1, 1)]);
     }
 }
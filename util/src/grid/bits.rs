@@ -0,0 +1,270 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point};
+use std::fmt::Display;
+use std::ops::{BitAnd, BitOr, BitXor};
+
+// Boolean grid packed one bit per cell - each row is stored in its own run
+// of u64 words (bit i of the row is bit i % 64 of word i / 64) and unused
+// bits at the end of a row are always zero, so the derived Hash/Eq only
+// look at the cells and grids can be used as HashMap keys directly
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    pub start: Point,
+    pub end: Point,
+    pub size: Offset,
+    words: usize,
+    data: Vec<u64>,
+}
+
+impl BitGrid {
+    // All cells unset
+    pub fn new(start: &Point, end: &Point) -> Self {
+        let size = (*end - *start) + Offset::new(1, 1);
+        assert!(size.dx >= 0 && size.dy >= 0);
+        let words = (size.dx as usize).div_ceil(64);
+        BitGrid {
+            start: *start,
+            end: *end,
+            size,
+            words,
+            data: vec![0; words * size.dy as usize],
+        }
+    }
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> bool) -> Self {
+        let mut g = BitGrid::new(&grid.start, &grid.end);
+        grid.iter_points()
+            .filter(|(_, v)| f(v))
+            .for_each(|(p, _)| g.set(&p, true).unwrap());
+        g
+    }
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut g = Grid::empty(&self.start, &self.end, false);
+        self.iter_ones().for_each(|p| g.set(&p, true).unwrap());
+        g
+    }
+    pub fn check_bounds(&self, p: &Point) -> bool {
+        (p.x >= self.start.x) && (p.y >= self.start.y) && (p.x <= self.end.x) && (p.y <= self.end.y)
+    }
+    // Word index and bit mask for p (which must be in bounds)
+    fn index(&self, p: &Point) -> (usize, u64) {
+        let o = *p - self.start;
+        let (x, y) = (o.dx as usize, o.dy as usize);
+        (y * self.words + x / 64, 1 << (x % 64))
+    }
+    pub fn get(&self, p: &Point) -> Option<bool> {
+        if self.check_bounds(p) {
+            let (i, mask) = self.index(p);
+            Some(self.data[i] & mask != 0)
+        } else {
+            None
+        }
+    }
+    #[allow(clippy::result_unit_err)]
+    pub fn set(&mut self, p: &Point, v: bool) -> Result<(), ()> {
+        if self.check_bounds(p) {
+            let (i, mask) = self.index(p);
+            if v {
+                self.data[i] |= mask;
+            } else {
+                self.data[i] &= !mask;
+            }
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|w| w.count_ones() as usize).sum()
+    }
+    // Set cells in row order
+    pub fn iter_ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.data.iter().enumerate().flat_map(move |(i, &w)| {
+            let (y, x) = (i / self.words, (i % self.words) * 64);
+            (0..64)
+                .filter(move |b| w & (1 << b) != 0)
+                .map(move |b| self.start + Offset::new((x + b) as i64, y as i64))
+        })
+    }
+    // Mask of the valid bits in the last word of each row
+    fn last_mask(&self) -> u64 {
+        match self.size.dx % 64 {
+            0 => u64::MAX,
+            n => (1 << n) - 1,
+        }
+    }
+    // Move every cell by o - cells moved off the grid are lost and the
+    // cells uncovered are unset. Rows are shifted a word at a time
+    pub fn shift(&self, o: Offset) -> Self {
+        let mut out = BitGrid::new(&self.start, &self.end);
+        let (w, h) = (self.words as i64, self.size.dy);
+        let (ws, bs) = (o.dx.abs() / 64, (o.dx.abs() % 64) as u32);
+        let word = |row: &[u64], j: i64| {
+            if j >= 0 && j < w {
+                row[j as usize]
+            } else {
+                0
+            }
+        };
+        for y in 0..h {
+            let from = y - o.dy;
+            if from < 0 || from >= h {
+                continue;
+            }
+            let row = &self.data[(from * w) as usize..((from + 1) * w) as usize];
+            for j in 0..w {
+                // Shifting right (+x) moves bits up within the words
+                out.data[(y * w + j) as usize] = if o.dx >= 0 {
+                    let hi = word(row, j - ws) << bs;
+                    let lo = if bs > 0 {
+                        word(row, j - ws - 1) >> (64 - bs)
+                    } else {
+                        0
+                    };
+                    hi | lo
+                } else {
+                    let lo = word(row, j + ws) >> bs;
+                    let hi = if bs > 0 {
+                        word(row, j + ws + 1) << (64 - bs)
+                    } else {
+                        0
+                    };
+                    hi | lo
+                };
+            }
+            if w > 0 {
+                out.data[((y + 1) * w - 1) as usize] &= self.last_mask();
+            }
+        }
+        out
+    }
+    fn zip(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(
+            (self.start, self.end),
+            (other.start, other.end),
+            "BitGrid bounds differ"
+        );
+        BitGrid {
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+// Bitwise operations between grids with the same bounds
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a ^ b)
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.start.y..=self.end.y {
+            for x in self.start.x..=self.end.x {
+                match self.get(&Point::new(x, y)) {
+                    Some(true) => write!(f, "#")?,
+                    _ => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn make_bits(s: &str) -> BitGrid {
+        let g = Grid::parse_str(s, |b| Ok::<_, &str>(b == b'#')).unwrap();
+        BitGrid::from_grid(&g, |&v| v)
+    }
+
+    #[test]
+    fn test_bitgrid() {
+        let mut g = BitGrid::new(&Point::new(-1, -1), &Point::new(69, 1));
+        assert_eq!(g.count_ones(), 0);
+        g.set(&Point::new(-1, -1), true).unwrap();
+        g.set(&Point::new(64, 0), true).unwrap();
+        g.set(&Point::new(69, 1), true).unwrap();
+        assert!(g.set(&Point::new(70, 1), true).is_err());
+        assert_eq!(g.get(&Point::new(64, 0)), Some(true));
+        assert_eq!(g.get(&Point::new(63, 0)), Some(false));
+        assert_eq!(g.get(&Point::new(0, 2)), None);
+        assert_eq!(g.count_ones(), 3);
+        assert_eq!(
+            g.iter_ones().collect::<Vec<_>>(),
+            vec![Point::new(-1, -1), Point::new(64, 0), Point::new(69, 1)]
+        );
+        g.set(&Point::new(64, 0), false).unwrap();
+        assert_eq!(g.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_bitgrid_convert() {
+        let s = "#..#\n.##.\n";
+        let g = make_bits(s);
+        assert_eq!(g.to_string(), s);
+        assert_eq!(g.to_grid().find(&true).len(), 4);
+    }
+
+    #[test]
+    fn test_bitgrid_shift() {
+        let g = make_bits("#..#\n.##.\n....\n");
+        assert_eq!(g.shift(Offset::new(1, 0)).to_string(), ".#..\n..##\n....\n");
+        assert_eq!(
+            g.shift(Offset::new(-1, 1)).to_string(),
+            "....\n..#.\n##..\n"
+        );
+        assert_eq!(
+            g.shift(Offset::new(0, -1)).to_string(),
+            ".##.\n....\n....\n"
+        );
+        assert_eq!(g.shift(Offset::new(4, 0)).count_ones(), 0);
+        // Shifts across word boundaries
+        let mut w = BitGrid::new(&Point::new(0, 0), &Point::new(199, 0));
+        [0, 63, 64, 130]
+            .iter()
+            .for_each(|&x| w.set(&Point::new(x, 0), true).unwrap());
+        let ones = |g: &BitGrid| g.iter_ones().map(|p| p.x).collect::<Vec<_>>();
+        assert_eq!(ones(&w.shift(Offset::new(70, 0))), vec![70, 133, 134]);
+        assert_eq!(ones(&w.shift(Offset::new(-63, 0))), vec![0, 1, 67]);
+        assert_eq!(ones(&w.shift(Offset::new(-128, 0))), vec![2]);
+        assert_eq!(ones(&w.shift(Offset::new(69, 0))), vec![69, 132, 133, 199]);
+    }
+
+    #[test]
+    fn test_bitgrid_ops() {
+        let a = make_bits("##..\n");
+        let b = make_bits(".##.\n");
+        assert_eq!((&a & &b).to_string(), ".#..\n");
+        assert_eq!((&a | &b).to_string(), "###.\n");
+        assert_eq!((&a ^ &b).to_string(), "#.#.\n");
+        let mut seen = HashSet::new();
+        assert!(seen.insert(a.clone()));
+        assert!(!seen.insert(make_bits("##..\n")));
+        assert!(seen.insert(a.shift(Offset::new(1, 0))));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::grid::render::{Colour, Render, Style};
+use crate::grid::Grid;
+use crate::point::Point;
+use std::fmt::Display;
+
+// Changed cell as (point, old value, new value)
+pub type Change<T> = (Point, T, T);
+
+impl<T: PartialEq + Clone> Grid<T> {
+    // Cells which differ in other (in row order) - the grids must have the
+    // same bounds
+    pub fn diff(&self, other: &Grid<T>) -> Vec<Change<T>> {
+        assert_eq!(
+            (self.start, self.end),
+            (other.start, other.end),
+            "grid bounds differ"
+        );
+        self.iter_points()
+            .zip(&other.data)
+            .filter(|((_, a), b)| a != b)
+            .map(|((p, a), b)| (p, a.clone(), b.clone()))
+            .collect()
+    }
+    // Set each changed cell to its new value. Nothing is changed if any cell
+    // is out of bounds or doesn't have the old value (which is returned)
+    pub fn apply(&mut self, diff: &[Change<T>]) -> Result<(), Point> {
+        self.patch(diff.iter().map(|(p, old, new)| (p, old, new)))
+    }
+    // Undo apply
+    pub fn revert(&mut self, diff: &[Change<T>]) -> Result<(), Point> {
+        self.patch(diff.iter().rev().map(|(p, old, new)| (p, new, old)))
+    }
+    fn patch<'a>(
+        &mut self,
+        changes: impl Iterator<Item = (&'a Point, &'a T, &'a T)> + Clone,
+    ) -> Result<(), Point>
+    where
+        T: 'a,
+    {
+        if let Some((p, _, _)) = changes.clone().find(|(p, old, _)| self.get(p) != Some(old)) {
+            return Err(*p);
+        }
+        for (p, _, new) in changes {
+            self.set(p, new.clone()).unwrap();
+        }
+        Ok(())
+    }
+}
+
+impl<T: PartialEq + Clone + Display> Grid<T> {
+    // other with the cells changed from self highlighted
+    pub fn render_diff<'a>(&self, other: &'a Grid<T>) -> Render<'a, T> {
+        let changed = self.diff(other).into_iter().map(|(p, _, _)| p);
+        other
+            .render()
+            .overlay(changed, Style::colour(Colour::Red).bold())
+            .colour(true)
+    }
+    // Rows of both grids next to each other with changed rows marked
+    pub fn side_by_side(&self, other: &Grid<T>) -> String {
+        let changed = self.diff(other);
+        let mut out = String::new();
+        for y in self.start.y..=self.end.y {
+            let left = self.row(y).map(|(_, v)| v.to_string()).collect::<String>();
+            let right = other.row(y).map(|(_, v)| v.to_string()).collect::<String>();
+            let mark = if changed.iter().any(|(p, _, _)| p.y == y) {
+                " <"
+            } else {
+                ""
+            };
+            out += &format!("{} | {}{}\n", left, right, mark);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::char_grid;
+
+    #[test]
+    fn test_diff() {
+        let a = char_grid("O..\n.#.\n..O\n");
+        let b = char_grid("...\n.#O\nO..\n");
+        let d = a.diff(&b);
+        assert_eq!(
+            d,
+            vec![
+                (Point::new(0, 0), 'O', '.'),
+                (Point::new(2, 1), '.', 'O'),
+                (Point::new(0, 2), '.', 'O'),
+                (Point::new(2, 2), 'O', '.'),
+            ]
+        );
+        assert!(a.diff(&a).is_empty());
+        let mut c = a.clone();
+        c.apply(&d).unwrap();
+        assert!(c.diff(&b).is_empty());
+        c.revert(&d).unwrap();
+        assert!(c.diff(&a).is_empty());
+        // Applying twice fails (without changing anything)
+        c.apply(&d).unwrap();
+        assert_eq!(c.apply(&d), Err(Point::new(0, 0)));
+        assert!(c.diff(&b).is_empty());
+        assert_eq!(
+            c.apply(&[(Point::new(5, 5), '.', 'O')]),
+            Err(Point::new(5, 5))
+        );
+    }
+
+    #[test]
+    fn test_diff_render() {
+        let a = char_grid("O.\n.#\n");
+        let b = char_grid(".O\n.#\n");
+        assert_eq!(a.side_by_side(&b), "O. | .O <\n.# | .#\n");
+        assert_eq!(
+            a.render_diff(&b).to_string(),
+            "\x1b[1;31m.\x1b[0m\x1b[1;31mO\x1b[0m\n.#\n"
+        );
+        assert_eq!(a.render_diff(&b).colour(false).to_string(), b.to_string());
+    }
+}
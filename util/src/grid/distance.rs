@@ -0,0 +1,132 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use std::collections::VecDeque;
+
+// Multi-source BFS over the grid - sources are at distance 0 (whether or not
+// they are passable), other cells are only entered if passable and
+// unreachable cells are None
+impl<T> Grid<T> {
+    pub fn distance_map(
+        &self,
+        sources: &[Point],
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.distance_map_limit(sources, passable, u32::MAX)
+    }
+    // Stop searching at max steps (cells further away are None)
+    pub fn distance_map_limit(
+        &self,
+        sources: &[Point],
+        passable: impl Fn(&T) -> bool,
+        max: u32,
+    ) -> Grid<Option<u32>> {
+        let mut dist = self.map(|_| None);
+        bfs(
+            sources,
+            max,
+            |p| {
+                self.adjacent(p)
+                    .into_iter()
+                    .filter(|n| passable(self.get(n).unwrap()))
+                    .collect()
+            },
+            |p, d| match dist.get_mut(p) {
+                Some(v @ None) => {
+                    *v = Some(d);
+                    true
+                }
+                _ => false,
+            },
+        );
+        dist
+    }
+    // Number of cells within max steps at an even and odd distance
+    pub fn parity_counts(
+        &self,
+        sources: &[Point],
+        passable: impl Fn(&T) -> bool,
+        max: u32,
+    ) -> [usize; 2] {
+        self.distance_map_limit(sources, passable, max)
+            .data
+            .iter()
+            .flatten()
+            .fold([0, 0], |mut c, d| {
+                c[(d % 2) as usize] += 1;
+                c
+            })
+    }
+    // Cells which can be reached in exactly n steps - anything within n
+    // steps with the same parity (as we can always step back and forth)
+    pub fn reachable_in(&self, sources: &[Point], passable: impl Fn(&T) -> bool, n: u32) -> usize {
+        self.parity_counts(sources, passable, n)[(n % 2) as usize]
+    }
+}
+
+// BFS out to max steps shared by Grid and TiledGrid - neighbours are the
+// passable points next to a point and visit records the distance to a
+// point, returning false if it already has one (or is out of bounds)
+pub(crate) fn bfs(
+    sources: &[Point],
+    max: u32,
+    neighbours: impl Fn(&Point) -> Vec<Point>,
+    mut visit: impl FnMut(&Point, u32) -> bool,
+) {
+    let mut q = VecDeque::new();
+    for p in sources {
+        if visit(p, 0) {
+            q.push_back((*p, 0));
+        }
+    }
+    while let Some((p, d)) = q.pop_front() {
+        if d == max {
+            continue;
+        }
+        for n in neighbours(&p) {
+            if visit(&n, d + 1) {
+                q.push_back((n, d + 1));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_grid() -> Grid<char> {
+        Grid::parse_str("...#.\n.#.#.\n.#...\n", |b| Ok::<_, &str>(b as char)).unwrap()
+    }
+
+    fn show(d: &Grid<Option<u32>>) -> String {
+        d.map(|d| match d {
+            Some(d) => char::from_digit(*d, 36).unwrap(),
+            None => '-',
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_distance_map() {
+        let g = make_grid();
+        let d = g.distance_map(&[Point::new(0, 0)], |&c| c == '.');
+        assert_eq!(show(&d), "012-8\n1-3-7\n2-456\n");
+        let d = g.distance_map(&[Point::new(0, 2), Point::new(4, 0)], |&c| c == '.');
+        assert_eq!(show(&d), "234-0\n1-5-1\n0-432\n");
+        let d = g.distance_map_limit(&[Point::new(0, 0)], |&c| c == '.', 3);
+        assert_eq!(show(&d), "012--\n1-3--\n2----\n");
+        // Out of bounds sources are ignored
+        let d = g.distance_map(&[Point::new(-1, 0)], |_| true);
+        assert!(d.data.iter().all(|d| d.is_none()));
+    }
+
+    #[test]
+    fn test_reachable_in() {
+        let g = make_grid();
+        let start = [Point::new(0, 0)];
+        assert_eq!(g.parity_counts(&start, |&c| c == '.', 4), [4, 3]);
+        assert_eq!(g.reachable_in(&start, |&c| c == '.', 3), 3);
+        assert_eq!(g.reachable_in(&start, |&c| c == '.', 4), 4);
+        assert_eq!(g.reachable_in(&start, |&c| c == '.', 100), 6);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::grid::bits::BitGrid;
+use crate::grid::Grid;
+use crate::point::{Neighbourhood, Point};
+use std::fmt::Display;
+
+// What to do when a fill reaches the edge of the grid - Stop treats the
+// outside as wall, Leak fails (eg. to tell an enclosed region from an
+// open one)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Stop,
+    Leak,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FillError {
+    OutOfBounds(Point),
+    Impassable(Point),
+    // First cell found on the edge of the grid
+    Leaked(Point),
+}
+
+impl Display for FillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FillError::OutOfBounds(p) => write!(f, "fill start {} is out of bounds", p),
+            FillError::Impassable(p) => write!(f, "fill start {} is not passable", p),
+            FillError::Leaked(p) => write!(f, "fill leaked out of the grid at {}", p),
+        }
+    }
+}
+
+impl std::error::Error for FillError {}
+
+impl<T> Grid<T> {
+    // Points of the region containing start (in BFS order) - each cell is
+    // queued at most once so this is linear in the region size
+    pub fn flood(
+        &self,
+        start: &Point,
+        passable: impl Fn(&T) -> bool,
+        neighbourhood: Neighbourhood,
+        edge: Edge,
+    ) -> Result<Vec<Point>, FillError> {
+        match self.get(start) {
+            None => return Err(FillError::OutOfBounds(*start)),
+            Some(v) if !passable(v) => return Err(FillError::Impassable(*start)),
+            _ => {}
+        }
+        let offsets = neighbourhood.offsets();
+        let mut visited = BitGrid::new(&self.start, &self.end);
+        visited.set(start, true).unwrap();
+        let mut region = vec![*start];
+        let mut i = 0;
+        while i < region.len() {
+            let p = region[i];
+            i += 1;
+            for o in &offsets {
+                let n = p + *o;
+                match visited.get(&n) {
+                    None if edge == Edge::Leak => return Err(FillError::Leaked(p)),
+                    Some(false) if passable(self.get(&n).unwrap()) => {
+                        visited.set(&n, true).unwrap();
+                        region.push(n);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(region)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // Flood the region containing start with value returning the points
+    // filled (as for flood) - nothing is changed on error
+    pub fn fill(
+        &mut self,
+        start: &Point,
+        passable: impl Fn(&T) -> bool,
+        neighbourhood: Neighbourhood,
+        edge: Edge,
+        value: T,
+    ) -> Result<Vec<Point>, FillError> {
+        let region = self.flood(start, passable, neighbourhood, edge)?;
+        for p in &region {
+            self.set(p, value.clone()).unwrap();
+        }
+        Ok(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_grid() -> Grid<char> {
+        Grid::parse_str("......\n.###..\n.#..#.\n.#.##.\n.##...\n......\n", |b| {
+            Ok::<_, &str>(b as char)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_flood() {
+        let g = make_grid();
+        let open = |&c: &char| c == '.';
+        let inner = Point::new(2, 2);
+        let r = g.flood(&inner, open, Neighbourhood::VonNeumann, Edge::Leak);
+        assert_eq!(r.map(|r| r.len()), Ok(3));
+        // The diagonal gap lets an 8-connected fill out
+        let r = g.flood(&inner, open, Neighbourhood::Moore, Edge::Leak);
+        assert!(matches!(r, Err(FillError::Leaked(_))));
+        let r = g.flood(&inner, open, Neighbourhood::Moore, Edge::Stop);
+        assert_eq!(r.map(|r| r.len()), Ok(g.find(&'.').len()));
+        let outer = g.flood(
+            &Point::new(0, 0),
+            open,
+            Neighbourhood::VonNeumann,
+            Edge::Stop,
+        );
+        assert_eq!(outer.map(|r| r.len()), Ok(g.find(&'.').len() - 3));
+    }
+
+    #[test]
+    fn test_flood_errors() {
+        let g = make_grid();
+        let open = |&c: &char| c == '.';
+        let e = g.flood(
+            &Point::new(1, 1),
+            open,
+            Neighbourhood::VonNeumann,
+            Edge::Stop,
+        );
+        assert_eq!(e, Err(FillError::Impassable(Point::new(1, 1))));
+        let e = g.flood(
+            &Point::new(6, 0),
+            open,
+            Neighbourhood::VonNeumann,
+            Edge::Stop,
+        );
+        assert_eq!(e, Err(FillError::OutOfBounds(Point::new(6, 0))));
+        let e = g.flood(
+            &Point::new(0, 0),
+            open,
+            Neighbourhood::VonNeumann,
+            Edge::Leak,
+        );
+        assert_eq!(e, Err(FillError::Leaked(Point::new(0, 0))));
+        assert_eq!(
+            e.unwrap_err().to_string(),
+            "fill leaked out of the grid at (0,0)"
+        );
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut g = make_grid();
+        let open = |&c: &char| c == '.';
+        let inner = Point::new(2, 2);
+        let e = g.fill(&inner, open, Neighbourhood::Moore, Edge::Leak, 'x');
+        assert!(matches!(e, Err(FillError::Leaked(_))));
+        assert!(g.find(&'x').is_empty());
+        let r = g.fill(&inner, open, Neighbourhood::VonNeumann, Edge::Leak, 'x');
+        assert_eq!(r.map(|r| r.len()), Ok(3));
+        assert_eq!(g.find(&'x').len(), 3);
+    }
+}
@@ -0,0 +1,356 @@
+use crate::grid::Grid;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::ErrorKind::InvalidInput;
+use std::io::{BufWriter, Error, Write};
+
+pub type Rgb = [u8; 3];
+
+// RGB image (one pixel per grid cell) with dependency-free PPM/PNG writers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    pub fn to_image(&self, colour: impl Fn(&T) -> Rgb) -> Image {
+        Image {
+            width: self.size.dx as usize,
+            height: self.size.dy as usize,
+            pixels: self.data.iter().map(colour).collect(),
+        }
+    }
+    pub fn write_ppm(&self, w: &mut impl Write, colour: impl Fn(&T) -> Rgb) -> std::io::Result<()> {
+        self.to_image(colour).write_ppm(w)
+    }
+    pub fn write_png(&self, w: &mut impl Write, colour: impl Fn(&T) -> Rgb) -> std::io::Result<()> {
+        self.to_image(colour).write_png(w)
+    }
+}
+
+impl Image {
+    // Binary (P6) PPM
+    pub fn write_ppm(&self, w: &mut impl Write) -> std::io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.pixels.concat())
+    }
+    // 8 bit RGB PNG - the image data is stored uncompressed (deflate
+    // allows raw blocks) so no compressor is needed
+    pub fn write_png(&self, w: &mut impl Write) -> std::io::Result<()> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0); // Filter type: none
+            raw.extend(row.concat());
+        }
+        let mut zlib = vec![0x78, 0x01];
+        let blocks = raw.chunks(0xffff).collect::<Vec<_>>();
+        for (i, block) in blocks.iter().enumerate() {
+            let len = block.len() as u16;
+            zlib.push((i == blocks.len() - 1) as u8);
+            zlib.extend(len.to_le_bytes());
+            zlib.extend((!len).to_le_bytes());
+            zlib.extend(*block);
+        }
+        if blocks.is_empty() {
+            zlib.extend([1, 0, 0, 0xff, 0xff]);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+        let mut ihdr = vec![];
+        ihdr.extend((self.width as u32).to_be_bytes());
+        ihdr.extend((self.height as u32).to_be_bytes());
+        ihdr.extend([8, 2, 0, 0, 0]); // 8 bit RGB, no interlace
+        w.write_all(b"\x89PNG\r\n\x1a\n")?;
+        png_chunk(w, b"IHDR", &ihdr)?;
+        png_chunk(w, b"IDAT", &zlib)?;
+        png_chunk(w, b"IEND", &[])
+    }
+}
+
+fn png_chunk(w: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> std::io::Result<()> {
+    w.write_all(&(data.len() as u32).to_be_bytes())?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    w.write_all(&crc32(&[kind, data].concat()).to_be_bytes())
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1_u32, 0_u32), |(a, b), &d| {
+        let a = (a + d as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+// Sequence of same sized frames (eg. each step of a simulation) which can
+// be written as numbered PNGs or an animated GIF
+#[derive(Debug, Clone, Default)]
+pub struct Frames {
+    pub frames: Vec<Image>,
+}
+
+impl Frames {
+    pub fn new() -> Self {
+        Frames { frames: vec![] }
+    }
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    pub fn record<T>(&mut self, g: &Grid<T>, colour: impl Fn(&T) -> Rgb) {
+        self.frames.push(g.to_image(colour));
+    }
+    // Writes <prefix>0000.png, <prefix>0001.png ...
+    pub fn write_pngs(&self, prefix: &str) -> std::io::Result<()> {
+        for (i, f) in self.frames.iter().enumerate() {
+            let mut w = BufWriter::new(File::create(format!("{}{:04}.png", prefix, i))?);
+            f.write_png(&mut w)?;
+        }
+        Ok(())
+    }
+    // Looping GIF with delay in 1/100s between frames - all frames must be
+    // the same size with at most 256 colours between them
+    pub fn write_gif(&self, w: &mut impl Write, delay: u16) -> std::io::Result<()> {
+        let (width, height) = match self.frames.first() {
+            Some(f) => (f.width, f.height),
+            None => return Err(Error::new(InvalidInput, "no frames")),
+        };
+        if self
+            .frames
+            .iter()
+            .any(|f| (f.width, f.height) != (width, height))
+        {
+            return Err(Error::new(InvalidInput, "frames differ in size"));
+        }
+        if width > 0xffff || height > 0xffff {
+            return Err(Error::new(InvalidInput, "image too large for GIF"));
+        }
+        let mut palette: Vec<Rgb> = vec![];
+        let mut index: HashMap<Rgb, u8> = HashMap::new();
+        for p in self.frames.iter().flat_map(|f| &f.pixels) {
+            if !index.contains_key(p) {
+                if palette.len() == 256 {
+                    return Err(Error::new(InvalidInput, "more than 256 colours"));
+                }
+                index.insert(*p, palette.len() as u8);
+                palette.push(*p);
+            }
+        }
+        // Colour table has 2^bits entries (at least 4 for the LZW code size)
+        let bits = (palette.len().max(4) as u32 - 1).ilog2() + 1;
+        palette.resize(1 << bits, [0, 0, 0]);
+        w.write_all(b"GIF89a")?;
+        w.write_all(&(width as u16).to_le_bytes())?;
+        w.write_all(&(height as u16).to_le_bytes())?;
+        w.write_all(&[0xf0 | (bits as u8 - 1), 0, 0])?;
+        w.write_all(&palette.concat())?;
+        w.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+        for f in &self.frames {
+            w.write_all(&[0x21, 0xf9, 4, 0])?;
+            w.write_all(&delay.to_le_bytes())?;
+            w.write_all(&[0, 0, 0x2c, 0, 0, 0, 0])?;
+            w.write_all(&(width as u16).to_le_bytes())?;
+            w.write_all(&(height as u16).to_le_bytes())?;
+            w.write_all(&[0, bits as u8])?;
+            let pixels = f.pixels.iter().map(|p| index[p]).collect::<Vec<_>>();
+            for block in lzw(&pixels, bits).chunks(255) {
+                w.write_all(&[block.len() as u8])?;
+                w.write_all(block)?;
+            }
+            w.write_all(&[0])?;
+        }
+        w.write_all(&[0x3b])
+    }
+}
+
+// Packs variable width codes LSB first
+struct Bits {
+    out: Vec<u8>,
+    acc: u32,
+    n: u32,
+}
+
+impl Bits {
+    fn push(&mut self, code: u16, size: u32) {
+        self.acc |= (code as u32) << self.n;
+        self.n += size;
+        while self.n >= 8 {
+            self.out.push(self.acc as u8);
+            self.acc >>= 8;
+            self.n -= 8;
+        }
+    }
+    fn finish(mut self) -> Vec<u8> {
+        if self.n > 0 {
+            self.out.push(self.acc as u8);
+        }
+        self.out
+    }
+}
+
+// GIF variant of LZW - variable width codes (up to 12 bits) with a clear
+// code when the table is full
+fn lzw(data: &[u8], min_size: u32) -> Vec<u8> {
+    let (clear, eoi) = (1_u16 << min_size, (1_u16 << min_size) + 1);
+    let mut bits = Bits {
+        out: vec![],
+        acc: 0,
+        n: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let (mut next, mut size) = (eoi + 1, min_size + 1);
+    bits.push(clear, size);
+    let mut iter = data.iter();
+    if let Some(&first) = iter.next() {
+        let mut prefix = first as u16;
+        for &k in iter {
+            if let Some(&code) = table.get(&(prefix, k)) {
+                prefix = code;
+                continue;
+            }
+            bits.push(prefix, size);
+            // The decoder adds its table entry a code later so switches
+            // width when the next code reaches the current limit
+            if next == 1 << size && size < 12 {
+                size += 1;
+            }
+            table.insert((prefix, k), next);
+            next += 1;
+            if next == 4096 {
+                bits.push(clear, size);
+                table.clear();
+                (next, size) = (eoi + 1, min_size + 1);
+            }
+            prefix = k as u16;
+        }
+        bits.push(prefix, size);
+    }
+    bits.push(eoi, size);
+    bits.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+    const WHITE: Rgb = [255, 255, 255];
+
+    fn make_grid() -> Grid<bool> {
+        Grid::parse_str("#..\n.#.\n", |b| Ok::<_, &str>(b == b'#')).unwrap()
+    }
+
+    fn colour(&v: &bool) -> Rgb {
+        if v {
+            RED
+        } else {
+            WHITE
+        }
+    }
+
+    // Decode GIF LZW data back to indices (for checking the encoder)
+    fn unlzw(data: &[u8], min_size: u32) -> Vec<u8> {
+        let (clear, eoi) = (1_usize << min_size, (1_usize << min_size) + 1);
+        let reset = || (0..clear).map(|i| vec![i as u8]).collect::<Vec<_>>();
+        let (mut table, mut size) = (reset(), min_size + 1);
+        let (mut out, mut prev): (Vec<u8>, Option<Vec<u8>>) = (vec![], None);
+        let mut pos = 0;
+        loop {
+            let code = (0..size).fold(0, |c, i| {
+                let bit = (data[(pos + i as usize) / 8] >> ((pos + i as usize) % 8)) & 1;
+                c | (bit as usize) << i
+            });
+            pos += size as usize;
+            if code == clear {
+                (table, size, prev) = (reset(), min_size + 1, None);
+                table.extend([vec![], vec![]]);
+                continue;
+            } else if code == eoi {
+                return out;
+            }
+            let entry = match (table.get(code), &prev) {
+                (Some(e), _) => e.clone(),
+                (None, Some(p)) => [p.clone(), vec![p[0]]].concat(),
+                _ => panic!("invalid code"),
+            };
+            if let Some(p) = prev {
+                if table.len() < 4096 {
+                    table.push([p, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+            out.extend(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = vec![];
+        make_grid().write_ppm(&mut out, colour).unwrap();
+        assert_eq!(&out[..11], b"P6\n3 2\n255\n");
+        assert_eq!(out.len(), 11 + 3 * 6);
+        assert_eq!(&out[11..17], &[255, 0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_png() {
+        let mut out = vec![];
+        make_grid().write_png(&mut out, colour).unwrap();
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&out[12..16], b"IHDR");
+        assert_eq!(&out[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&out[out.len() - 8..out.len() - 4], b"IEND");
+        // Check values from the PNG spec / zlib
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_lzw() {
+        for (data, bits) in [
+            (vec![], 2),
+            (vec![0, 1, 0, 1, 0, 1, 2, 3, 3, 3, 3, 3, 3, 0], 2),
+            ((0..20000).map(|i| ((i * i) % 251) as u8).collect(), 8),
+            ((0..20000).map(|i| (i / 7 % 3) as u8).collect(), 2),
+        ] {
+            assert_eq!(unlzw(&lzw(&data, bits), bits), data);
+        }
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut frames = Frames::new();
+        let mut g = make_grid();
+        frames.record(&g, colour);
+        g.set(&crate::point::Point::new(2, 1), true).unwrap();
+        frames.record(&g, colour);
+        let mut out = vec![];
+        frames.write_gif(&mut out, 10).unwrap();
+        assert_eq!(&out[..10], b"GIF89a\x03\x00\x02\x00");
+        // 4 entry colour table (red, white + padding)
+        assert_eq!(out[10], 0xf1);
+        assert_eq!(&out[13..19], &[255, 0, 0, 255, 255, 255]);
+        assert_eq!(out.iter().filter(|&&b| b == 0x2c).count(), 2);
+        assert_eq!(out.last(), Some(&0x3b));
+        frames.record(&Grid::empty(&g.start, &g.end, false), colour);
+        frames.frames[2].width = 2;
+        assert!(frames.write_gif(&mut vec![], 10).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point};
+
+// Iterators yield cells with their points (in the grid's own coordinates,
+// so starting from start rather than 0,0) - row/col of a point outside
+// the grid is empty
+impl<T> Grid<T> {
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (self.index_to_point(i), v))
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let (start, width) = (self.start, self.size.dx);
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, v)| (start + Offset::new(i as i64 % width, i as i64 / width), v))
+    }
+    fn row_range(&self, y: i64) -> std::ops::Range<usize> {
+        if y >= self.start.y && y <= self.end.y {
+            let i = ((y - self.start.y) * self.size.dx) as usize;
+            i..i + self.size.dx as usize
+        } else {
+            0..0
+        }
+    }
+    pub fn row(&self, y: i64) -> impl Iterator<Item = (Point, &T)> {
+        let x = self.start.x;
+        self.data[self.row_range(y)]
+            .iter()
+            .enumerate()
+            .map(move |(i, v)| (Point::new(x + i as i64, y), v))
+    }
+    pub fn row_mut(&mut self, y: i64) -> impl Iterator<Item = (Point, &mut T)> {
+        let (x, range) = (self.start.x, self.row_range(y));
+        self.data[range]
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, v)| (Point::new(x + i as i64, y), v))
+    }
+    pub fn col(&self, x: i64) -> impl Iterator<Item = (Point, &T)> {
+        let ys = if x >= self.start.x && x <= self.end.x {
+            self.start.y..self.end.y + 1
+        } else {
+            0..0
+        };
+        ys.map(move |y| {
+            let p = Point::new(x, y);
+            (p, self.get(&p).unwrap())
+        })
+    }
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        (self.start.y..=self.end.y).map(|y| self.row(y))
+    }
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        (self.start.x..=self.end.x).map(|x| self.col(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::abc_grid;
+
+    // Digits 1-6 rather than letters so they can be summed
+    fn make_grid() -> Grid<u32> {
+        abc_grid(Point::new(-1, -1)).map(|&c| c as u32 - 'A' as u32 + 1)
+    }
+
+    #[test]
+    fn test_iter_points() {
+        let g = make_grid();
+        let v = g.iter_points().collect::<Vec<_>>();
+        assert_eq!(v.len(), 6);
+        assert_eq!(v[0], (Point::new(-1, -1), &1));
+        assert_eq!(v[4], (Point::new(0, 0), &5));
+        assert!(v.iter().all(|(p, c)| g.get(p) == Some(c)));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut g = make_grid();
+        g.iter_mut().for_each(|(p, v)| *v += (p.x * 10 + 20) as u32);
+        assert_eq!(g.data, vec![11, 22, 33, 14, 25, 36]);
+        g.row_mut(0).for_each(|(_, v)| *v = 0);
+        assert_eq!(g.data, vec![11, 22, 33, 0, 0, 0]);
+        assert_eq!(g.row_mut(1).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_cols() {
+        let g = make_grid();
+        let values =
+            |i: &mut dyn Iterator<Item = (Point, &u32)>| i.map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(values(&mut g.row(0)), vec![4, 5, 6]);
+        assert_eq!(values(&mut g.col(1)), vec![3, 6]);
+        assert_eq!(g.row(0).next(), Some((Point::new(-1, 0), &4)));
+        assert_eq!(g.col(1).last(), Some((Point::new(1, 0), &6)));
+        assert_eq!(g.row(1).count(), 0);
+        assert_eq!(g.col(-2).count(), 0);
+        assert_eq!(
+            g.rows()
+                .map(|r| r.map(|(_, v)| v).sum())
+                .collect::<Vec<u32>>(),
+            vec![6, 15]
+        );
+        assert_eq!(
+            g.cols()
+                .map(|c| c.map(|(_, v)| v).sum())
+                .collect::<Vec<u32>>(),
+            vec![5, 7, 9]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::grid::Grid;
+use crate::point::{Point, ADJACENT};
+use std::cmp::{max, min};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionStats {
+    pub area: usize,
+    // Cell edges shared with another region or the edge of the grid
+    pub perimeter: usize,
+    // Bounding box
+    pub start: Point,
+    pub end: Point,
+    pub border: bool,
+}
+
+impl<T> Grid<T> {
+    // Label connected regions - adjacent cells a, b are joined if
+    // same_region(a, b). Returns the region id of every cell (ids are
+    // numbered in row order of each region's first cell) with the stats for
+    // each region indexed by id
+    pub fn label_regions(
+        &self,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, Vec<RegionStats>) {
+        let mut labels = self.map(|_| usize::MAX);
+        let mut regions = vec![];
+        for i in 0..self.data.len() {
+            if labels.data[i] != usize::MAX {
+                continue;
+            }
+            let (id, seed) = (regions.len(), self.index_to_point(i));
+            let mut stats = RegionStats {
+                area: 0,
+                perimeter: 0,
+                start: seed,
+                end: seed,
+                border: false,
+            };
+            labels.data[i] = id;
+            let mut q = vec![seed];
+            while let Some(p) = q.pop() {
+                stats.area += 1;
+                stats.start = Point::new(min(stats.start.x, p.x), min(stats.start.y, p.y));
+                stats.end = Point::new(max(stats.end.x, p.x), max(stats.end.y, p.y));
+                let v = self.get(&p).unwrap();
+                for n in self.adjacent(&p) {
+                    if labels.get(&n) == Some(&usize::MAX) && same_region(v, self.get(&n).unwrap())
+                    {
+                        labels.set(&n, id).unwrap();
+                        q.push(n);
+                    }
+                }
+            }
+            stats.border = stats.start.x == self.start.x
+                || stats.start.y == self.start.y
+                || stats.end.x == self.end.x
+                || stats.end.y == self.end.y;
+            regions.push(stats);
+        }
+        // Perimeter needs the final labels as a non-transitive same_region
+        // can leave neighbouring cells in different regions
+        for (p, &id) in labels.iter_points() {
+            regions[id].perimeter += ADJACENT
+                .iter()
+                .filter(|&&o| labels.get(&(p + o)) != Some(&id))
+                .count();
+        }
+        (labels, regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_regions() {
+        let g = Grid::parse_str("AAAA\nBBCD\nBBCC\nEEEC\n", |b| Ok::<_, &str>(b as char)).unwrap();
+        let (labels, regions) = g.label_regions(|a, b| a == b);
+        assert_eq!(
+            labels
+                .map(|&id| char::from_digit(id as u32, 10).unwrap())
+                .to_string(),
+            "0000\n1123\n1122\n4442\n"
+        );
+        assert_eq!(
+            regions
+                .iter()
+                .map(|r| (r.area, r.perimeter))
+                .collect::<Vec<_>>(),
+            vec![(4, 10), (4, 8), (4, 10), (1, 4), (3, 8)]
+        );
+        assert_eq!(
+            (regions[2].start, regions[2].end),
+            (Point::new(2, 1), Point::new(3, 3))
+        );
+        assert!(regions.iter().all(|r| r.border));
+    }
+
+    #[test]
+    fn test_label_regions_enclosed() {
+        let g = Grid::parse_str("OOOOO\nOXOXO\nOOOOO\n", |b| Ok::<_, &str>(b as char)).unwrap();
+        let (labels, regions) = g.label_regions(|a, b| a == b);
+        assert_eq!(regions.len(), 3);
+        assert_eq!(labels.get(&Point::new(3, 1)), Some(&2));
+        assert_eq!(regions[0].area, 13);
+        assert_eq!(regions[0].perimeter, 16 + 8);
+        assert!(regions[0].border);
+        assert!(!regions[1].border && !regions[2].border);
+        assert_eq!(regions[1].perimeter, 4);
+        // Any relation between neighbours can be used (here joining
+        // neighbouring digits which differ by at most one)
+        let g = Grid::parse_str("1239\n5678\n", |b| Ok::<_, &str>(b - b'0')).unwrap();
+        let (_, regions) = g.label_regions(|a, b| a.abs_diff(*b) <= 1);
+        assert_eq!(
+            regions.iter().map(|r| r.area).collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+// How to draw a cell - unset fields fall through to whatever is underneath
+// (an overlay over a style closure over the cell's own Display)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub ch: Option<char>,
+    pub fg: Option<Colour>,
+    pub bg: Option<Colour>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn char(ch: char) -> Self {
+        Style {
+            ch: Some(ch),
+            ..Default::default()
+        }
+    }
+    pub fn colour(fg: Colour) -> Self {
+        Style {
+            fg: Some(fg),
+            ..Default::default()
+        }
+    }
+    pub fn on(self, bg: Colour) -> Self {
+        Style {
+            bg: Some(bg),
+            ..self
+        }
+    }
+    pub fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+    fn over(self, under: Style) -> Self {
+        Style {
+            ch: self.ch.or(under.ch),
+            fg: self.fg.or(under.fg),
+            bg: self.bg.or(under.bg),
+            bold: self.bold || under.bold,
+        }
+    }
+    fn ansi(&self) -> Option<String> {
+        let mut codes = vec![];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(c) = self.fg {
+            codes.push((30 + c as u8).to_string());
+        }
+        if let Some(c) = self.bg {
+            codes.push((40 + c as u8).to_string());
+        }
+        (!codes.is_empty()).then(|| codes.join(";"))
+    }
+}
+
+type StyleFn<'a, T> = Box<dyn Fn(Point, &T) -> Style + 'a>;
+
+// Builder for annotated output (from Grid::render) eg.
+//   g.render().highlight(path, '*').colour(true).write_to(&mut stdout())
+// Overlays added later are drawn over earlier ones. Axes are shown by
+// default if the grid doesn't start at 0,0 and colours are off by default
+// (char overlays still apply)
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    overlay: HashMap<Point, Style>,
+    style: Option<StyleFn<'a, T>>,
+    axes: bool,
+    colour: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            overlay: HashMap::new(),
+            style: None,
+            axes: self.start != Point::new(0, 0),
+            colour: false,
+        }
+    }
+}
+
+impl<'a, T: Display> Render<'a, T> {
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, style: Style) -> Self {
+        for p in points {
+            let under = self.overlay.get(&p).copied().unwrap_or_default();
+            self.overlay.insert(p, style.over(under));
+        }
+        self
+    }
+    pub fn highlight(self, points: impl IntoIterator<Item = Point>, ch: char) -> Self {
+        self.overlay(points, Style::char(ch))
+    }
+    pub fn style(mut self, f: impl Fn(Point, &T) -> Style + 'a) -> Self {
+        self.style = Some(Box::new(f));
+        self
+    }
+    pub fn axes(mut self, axes: bool) -> Self {
+        self.axes = axes;
+        self
+    }
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+    pub fn write_to(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(w, "{}", self)
+    }
+    fn write_cell(&self, f: &mut std::fmt::Formatter<'_>, p: Point, v: &T) -> std::fmt::Result {
+        let mut style = self.style.as_ref().map(|s| s(p, v)).unwrap_or_default();
+        if let Some(o) = self.overlay.get(&p) {
+            style = o.over(style);
+        }
+        let code = if self.colour { style.ansi() } else { None };
+        if let Some(code) = &code {
+            write!(f, "\x1b[{}m", code)?;
+        }
+        match style.ch {
+            Some(c) => write!(f, "{}", c)?,
+            None => write!(f, "{}", v)?,
+        }
+        if code.is_some() {
+            write!(f, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let g = self.grid;
+        let ys = (g.start.y..=g.end.y)
+            .map(|y| y.to_string())
+            .collect::<Vec<_>>();
+        let margin = ys.iter().map(|y| y.len()).max().unwrap_or(0);
+        if self.axes {
+            // x coordinates are written vertically (right aligned) above
+            // each column
+            let xs = (g.start.x..=g.end.x)
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>();
+            let height = xs.iter().map(|x| x.len()).max().unwrap_or(0);
+            for i in 0..height {
+                write!(f, "{:margin$} ", "")?;
+                for x in &xs {
+                    let pad = height - x.len();
+                    let c = if i < pad {
+                        ' '
+                    } else {
+                        x.as_bytes()[i - pad] as char
+                    };
+                    write!(f, "{}", c)?;
+                }
+                writeln!(f)?
+            }
+        }
+        for (y, label) in (g.start.y..=g.end.y).zip(&ys) {
+            if self.axes {
+                write!(f, "{:>margin$} ", label)?;
+            }
+            for (p, v) in g.row(y) {
+                self.write_cell(f, p, v)?;
+            }
+            writeln!(f)?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_grid() -> Grid<char> {
+        Grid::parse_str("...\n.#.\n", |b| Ok::<_, &str>(b as char)).unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let g = make_grid();
+        assert_eq!(g.render().to_string(), g.to_string());
+        let r = g
+            .render()
+            .highlight([Point::new(0, 0), Point::new(1, 0)], '*')
+            .highlight([Point::new(1, 0)], '+');
+        assert_eq!(r.to_string(), "*+.\n.#.\n");
+        let r = g.render().style(|p, &c| {
+            if c == '#' || p.x == 2 {
+                Style::char('X')
+            } else {
+                Style::default()
+            }
+        });
+        assert_eq!(r.to_string(), "..X\n.XX\n");
+        let mut out = vec![];
+        r.overlay([Point::new(2, 0)], Style::colour(Colour::Red))
+            .write_to(&mut out)
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "..X\n.XX\n");
+    }
+
+    #[test]
+    fn test_render_colour() {
+        let g = make_grid();
+        let r = g
+            .render()
+            .overlay([Point::new(1, 1)], Style::colour(Colour::Red).bold())
+            .overlay([Point::new(1, 1)], Style::char('@').on(Colour::Blue))
+            .colour(true);
+        assert_eq!(r.to_string(), "...\n.\x1b[1;31;44m@\x1b[0m.\n");
+    }
+
+    #[test]
+    fn test_render_axes() {
+        let mut g = make_grid();
+        g.start = Point::new(-1, 9);
+        g.end = Point::new(1, 10);
+        assert_eq!(g.render().to_string(), "   -  \n   101\n 9 ...\n10 .#.\n");
+        assert_eq!(g.render().axes(false).to_string(), g.to_string());
+        assert_eq!(
+            make_grid().render().axes(true).to_string(),
+            "  012\n0 ...\n1 .#.\n"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::grid::Grid;
+use crate::point::Point;
+use std::collections::HashSet;
+
+impl<T> Grid<T> {
+    // Maximal horizontal runs of cells matching pred (in row order) as the
+    // points and the cells - runs don't continue onto the next row
+    pub fn runs(&self, pred: impl Fn(&T) -> bool) -> Vec<(Vec<Point>, &[T])> {
+        let width = self.size.dx as usize;
+        let mut out = vec![];
+        for (y, row) in self.data.chunks(width.max(1)).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if !pred(&row[x]) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < row.len() && pred(&row[x]) {
+                    x += 1;
+                }
+                let points = (start..x)
+                    .map(|x| self.index_to_point(y * width + x))
+                    .collect();
+                out.push((points, &row[start..x]));
+            }
+        }
+        out
+    }
+    // Maximal vertical runs (in column order) - the cells aren't contiguous
+    // so are returned as references
+    pub fn vertical_runs(&self, pred: impl Fn(&T) -> bool) -> Vec<(Vec<Point>, Vec<&T>)> {
+        let mut out = vec![];
+        for col in self.cols() {
+            let mut run: (Vec<Point>, Vec<&T>) = (vec![], vec![]);
+            for (p, v) in col {
+                if pred(v) {
+                    run.0.push(p);
+                    run.1.push(v);
+                } else if !run.0.is_empty() {
+                    out.push(std::mem::take(&mut run));
+                }
+            }
+            if !run.0.is_empty() {
+                out.push(run);
+            }
+        }
+        out
+    }
+    // Points in the grid touching a run (including diagonally) which aren't
+    // part of it, in row order - works for any set of points
+    pub fn run_neighbours(&self, run: &[Point]) -> Vec<Point> {
+        let cells = run.iter().collect::<HashSet<_>>();
+        let mut out = run
+            .iter()
+            .flat_map(|p| self.adjacent8(p))
+            .filter(|p| !cells.contains(p))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        out.sort_by_key(|p| (p.y, p.x));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_grid() -> Grid<char> {
+        Grid::parse_str("467..114\n...*....\n..35.633\n", |b| {
+            Ok::<_, &str>(b as char)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_runs() {
+        let g = make_grid();
+        let runs = g.runs(|c| c.is_ascii_digit());
+        assert_eq!(
+            runs.iter()
+                .map(|(_, s)| s.iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["467", "114", "35", "633"]
+        );
+        assert_eq!(runs[2].0, vec![Point::new(2, 2), Point::new(3, 2)]);
+        // Runs stop at the end of the row
+        assert_eq!(runs[3].0.last(), Some(&Point::new(7, 2)));
+        assert_eq!(g.runs(|&c| c == '*').len(), 1);
+        assert!(g.runs(|&c| c == '#').is_empty());
+    }
+
+    #[test]
+    fn test_vertical_runs() {
+        let g = make_grid();
+        let runs = g.vertical_runs(|&c| c != '.');
+        assert_eq!(
+            runs.iter()
+                .map(|(_, v)| v.iter().copied().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["4", "6", "7", "3", "*5", "1", "6", "1", "3", "4", "3"]
+        );
+        assert_eq!(runs[4].0, vec![Point::new(3, 1), Point::new(3, 2)]);
+    }
+
+    #[test]
+    fn test_run_neighbours() {
+        let g = make_grid();
+        let runs = g.runs(|c| c.is_ascii_digit());
+        let n = g.run_neighbours(&runs[0].0);
+        assert_eq!(n.len(), 5);
+        assert_eq!(n.first(), Some(&Point::new(3, 0)));
+        assert!(n.contains(&Point::new(3, 1)));
+        // Numbers next to a symbol
+        let parts = runs
+            .iter()
+            .filter(|(p, _)| {
+                g.run_neighbours(p)
+                    .iter()
+                    .any(|n| g.get(n).is_some_and(|&c| c == '*'))
+            })
+            .map(|(_, s)| s.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(parts, vec!["467", "35"]);
+    }
+}
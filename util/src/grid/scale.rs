@@ -0,0 +1,127 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point};
+
+// Scaled grids keep the original start point - cell p of the original grid
+// covers the factor x factor block from start + (p - start) * factor
+impl<T> Grid<T> {
+    // Replace every cell with a factor x factor block (rows of cells, eg. an
+    // array [[U; 3]; 3] for factor 3)
+    pub fn upscale<U: Clone, B, R>(&self, factor: usize, expand: impl Fn(&T) -> B) -> Grid<U>
+    where
+        B: AsRef<[R]>,
+        R: AsRef<[U]>,
+    {
+        let f = factor as i64;
+        let size = Offset::new(self.size.dx * f, self.size.dy * f);
+        let mut rows = vec![Vec::with_capacity(size.dx as usize); size.dy as usize];
+        for (i, v) in self.data.iter().enumerate() {
+            let block = expand(v);
+            assert_eq!(
+                block.as_ref().len(),
+                factor,
+                "expanded block has wrong height"
+            );
+            let y = (i as i64 / self.size.dx) as usize * factor;
+            for (dy, row) in block.as_ref().iter().enumerate() {
+                assert_eq!(row.as_ref().len(), factor, "expanded block has wrong width");
+                rows[y + dy].extend_from_slice(row.as_ref());
+            }
+        }
+        Grid {
+            start: self.start,
+            end: self.start + (size + Offset::new(-1, -1)),
+            size,
+            data: rows.concat(),
+        }
+    }
+    // Reduce every factor x factor block to a single cell - reduce gets the
+    // block's cells in row order. The grid size must be a multiple of factor
+    pub fn downsample<U>(&self, factor: usize, reduce: impl Fn(&[&T]) -> U) -> Grid<U> {
+        let f = factor as i64;
+        assert!(factor > 0, "downsample factor must be positive");
+        assert!(
+            self.size.dx % f == 0 && self.size.dy % f == 0,
+            "grid size not a multiple of factor"
+        );
+        let size = Offset::new(self.size.dx / f, self.size.dy / f);
+        let mut data = Vec::with_capacity((size.dx * size.dy) as usize);
+        let mut block = Vec::with_capacity(factor * factor);
+        for y in 0..size.dy {
+            for x in 0..size.dx {
+                block.clear();
+                for dy in 0..f {
+                    let i = ((y * f + dy) * self.size.dx + x * f) as usize;
+                    block.extend(&self.data[i..i + factor]);
+                }
+                data.push(reduce(&block));
+            }
+        }
+        Grid {
+            start: self.start,
+            end: self.start + (size + Offset::new(-1, -1)),
+            size,
+            data,
+        }
+    }
+    // Top left of the block covering p after upscaling by factor
+    pub fn upscale_point(&self, p: &Point, factor: usize) -> Point {
+        let o = *p - self.start;
+        self.start + o * factor as i64
+    }
+    // Cell of the grid after downsampling by factor which covers p
+    pub fn downsample_point(&self, p: &Point, factor: usize) -> Point {
+        assert!(factor > 0, "downsample factor must be positive");
+        let (o, f) = (*p - self.start, factor as i64);
+        self.start + Offset::new(o.dx.div_euclid(f), o.dy.div_euclid(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upscale() {
+        let mut g = Grid::parse_str("#.\n.#\n", |b| Ok::<_, &str>(b as char)).unwrap();
+        g.start = Point::new(-1, 2);
+        g.end = Point::new(0, 3);
+        let u = g.upscale(3, |&c| match c {
+            '#' => [['/', '-', '\\'], ['|', '#', '|'], ['\\', '-', '/']],
+            _ => [['.'; 3]; 3],
+        });
+        assert_eq!(
+            u.to_string(),
+            "/-\\...\n|#|...\n\\-/...\n.../-\\\n...|#|\n...\\-/\n"
+        );
+        assert_eq!((u.start, u.end), (g.start, Point::new(4, 7)));
+        let p = g.upscale_point(&Point::new(0, 3), 3);
+        assert_eq!((p, u.get(&p)), (Point::new(2, 5), Some(&'/')));
+        let centre = p + Offset::new(1, 1);
+        assert_eq!(u.get(&centre), Some(&'#'));
+        assert_eq!(u.downsample_point(&centre, 3), Point::new(0, 3));
+        assert_eq!(u.downsample_point(&u.start, 3), g.start);
+        // Vec blocks also work
+        let v = g.upscale(2, |&c| vec![vec![c == '#'; 2]; 2]);
+        assert_eq!(v.find(&true).len(), 8);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let g = Grid::parse_str("1234\n5678\n", |b| Ok::<_, &str>((b - b'0') as u32)).unwrap();
+        let d = g.downsample(2, |b| b.iter().copied().sum::<u32>());
+        assert_eq!(d.data, vec![14, 22]);
+        assert_eq!((d.start, d.end), (g.start, Point::new(1, 0)));
+        let d = g.downsample(1, |b| *b[0]);
+        assert_eq!(d.data, g.data);
+        // Round trip
+        let u = g.upscale(3, |&v| [[v; 3]; 3]);
+        assert_eq!(u.downsample(3, |b| *b[4]).data, g.data);
+    }
+
+    #[test]
+    #[should_panic(expected = "downsample factor must be positive")]
+    fn test_downsample_zero() {
+        let g = Grid::parse_str("12\n34\n", Ok::<_, &str>).unwrap();
+        g.downsample(0, |b| *b[0]);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::grid::Grid;
+use crate::point::{Offset, DOWN, LEFT, RIGHT, UP};
+
+impl<T> Grid<T> {
+    // Slide every movable cell as far as it will go in direction (one of
+    // UP/DOWN/LEFT/RIGHT), stopping at the edge, a blocking cell or another
+    // settled cell. Cells which are neither are empty space and swap places
+    // with the cells moving through them. Each row/column is settled in a
+    // single pass from the edge - returns the number of cells which moved
+    pub fn settle(
+        &mut self,
+        direction: Offset,
+        movable: impl Fn(&T) -> bool,
+        blocking: impl Fn(&T) -> bool,
+    ) -> usize {
+        let (w, h) = (self.size.dx as usize, self.size.dy as usize);
+        // Index of the kth cell of a line counting back from the edge
+        let (lines, len) = if direction.dx == 0 { (w, h) } else { (h, w) };
+        let index = |line: usize, k: usize| match direction {
+            UP => line + k * w,
+            DOWN => line + (h - 1 - k) * w,
+            LEFT => line * w + k,
+            RIGHT => line * w + (w - 1 - k),
+            _ => panic!("invalid settle direction {}", direction),
+        };
+        let mut moved = 0;
+        for line in 0..lines {
+            // Where the next movable cell ends up
+            let mut target = 0;
+            for k in 0..len {
+                let i = index(line, k);
+                if blocking(&self.data[i]) {
+                    target = k + 1;
+                } else if movable(&self.data[i]) {
+                    if target != k {
+                        self.data.swap(index(line, target), i);
+                        moved += 1;
+                    }
+                    target += 1;
+                }
+            }
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::char_grid;
+
+    #[test]
+    fn test_settle() {
+        let g = char_grid("O.#.O\n.O..O\nO.#..\n");
+        let settle = |d| {
+            let mut s = g.clone();
+            let moved = s.settle(d, |&c| c == 'O', |&c| c == '#');
+            (s.to_string(), moved)
+        };
+        assert_eq!(settle(UP), ("OO#.O\nO...O\n..#..\n".to_string(), 2));
+        assert_eq!(settle(DOWN), ("..#..\nO...O\nOO#.O\n".to_string(), 4));
+        assert_eq!(settle(LEFT), ("O.#O.\nOO...\nO.#..\n".to_string(), 3));
+        assert_eq!(settle(RIGHT), (".O#.O\n...OO\n.O#..\n".to_string(), 3));
+        // Settled grids don't move
+        let mut s = g.clone();
+        s.settle(UP, |&c| c == 'O', |&c| c == '#');
+        assert_eq!(s.settle(UP, |&c| c == 'O', |&c| c == '#'), 0);
+        // Cells which aren't movable or blocking are empty
+        let mut s = char_grid("..\nab\n");
+        assert_eq!(s.settle(UP, |&c| c == 'a', |_| false), 1);
+        assert_eq!(s.to_string(), "a.\n.b\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_settle_direction() {
+        char_grid("O.\n").settle(Offset::new(1, 1), |&c| c == 'O', |_| false);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::grid::distance::bfs;
+use crate::grid::Grid;
+use crate::point::{Offset, Point, ADJACENT};
+use crate::sparsegrid::SparseGrid;
+
+// The grid repeated infinitely in every direction - tile (0,0) is the grid
+// itself, tile (1,0) the copy to its right, (0,-1) the copy above etc.
+// Every point is in bounds so there is no bounds checking
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+borrowed_copy!(TiledGrid);
+
+impl<T> Grid<T> {
+    // Map any point back into the grid returning the wrapped point and the
+    // tile the original point was in
+    pub fn wrap(&self, p: &Point) -> (Point, Point) {
+        let o = *p - self.start;
+        let wrapped =
+            self.start + Offset::new(o.dx.rem_euclid(self.size.dx), o.dy.rem_euclid(self.size.dy));
+        let tile = Point::new(o.dx.div_euclid(self.size.dx), o.dy.div_euclid(self.size.dy));
+        (wrapped, tile)
+    }
+    pub fn wrapping_get(&self, p: &Point) -> &T {
+        self.get(&self.wrap(p).0).unwrap()
+    }
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid { grid: self }
+    }
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    pub fn get(&self, p: &Point) -> &'a T {
+        self.grid.wrapping_get(p)
+    }
+    pub fn wrap(&self, p: &Point) -> Point {
+        self.grid.wrap(p).0
+    }
+    pub fn tile(&self, p: &Point) -> Point {
+        self.grid.wrap(p).1
+    }
+    pub fn adjacent(&self, p: &Point) -> Vec<Point> {
+        self.offset(p, ADJACENT)
+    }
+    pub fn offset(&self, p: &Point, offsets: impl IntoIterator<Item = Offset>) -> Vec<Point> {
+        offsets.into_iter().map(|o| *p + o).collect()
+    }
+    pub fn neighbours_with_values(
+        &self,
+        p: &Point,
+        offsets: impl IntoIterator<Item = Offset>,
+    ) -> Vec<(Point, &'a T)> {
+        offsets
+            .into_iter()
+            .map(|o| (*p + o, self.get(&(*p + o))))
+            .collect()
+    }
+    // As Grid::distance_map_limit - the search has to be limited as the
+    // tiled grid is infinite so only the cells reached are set
+    pub fn distance_map_limit(
+        &self,
+        sources: &[Point],
+        passable: impl Fn(&T) -> bool,
+        max: u32,
+    ) -> SparseGrid<u32> {
+        let mut dist = SparseGrid::new();
+        bfs(
+            sources,
+            max,
+            |p| {
+                self.adjacent(p)
+                    .into_iter()
+                    .filter(|n| passable(self.get(n)))
+                    .collect()
+            },
+            |p, d| dist.get(p).is_none() && dist.set(p, d).is_ok(),
+        );
+        dist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::abc_grid;
+    use crate::point::Neighbourhood;
+
+    #[test]
+    fn test_wrap() {
+        let g = abc_grid(Point::new(-1, -1));
+        assert_eq!(
+            g.wrap(&Point::new(0, 0)),
+            (Point::new(0, 0), Point::new(0, 0))
+        );
+        assert_eq!(
+            g.wrap(&Point::new(2, 0)),
+            (Point::new(-1, 0), Point::new(1, 0))
+        );
+        assert_eq!(
+            g.wrap(&Point::new(-2, -2)),
+            (Point::new(1, 0), Point::new(-1, -1))
+        );
+        assert_eq!(
+            g.wrap(&Point::new(-5, 3)),
+            (Point::new(1, -1), Point::new(-2, 2))
+        );
+        assert_eq!(g.wrapping_get(&Point::new(-5, 3)), &'C');
+        assert_eq!(g.wrapping_get(&Point::new(8, -7)), &'A');
+    }
+
+    #[test]
+    fn test_tiled() {
+        let g = abc_grid(Point::new(-1, -1));
+        let t = g.tiled();
+        let row = (-4..5)
+            .map(|x| *t.get(&Point::new(x, 2)))
+            .collect::<String>();
+        assert_eq!(row, "DEFDEFDEF");
+        assert_eq!(t.tile(&Point::new(-4, 2)), Point::new(-1, 1));
+        assert_eq!(t.wrap(&Point::new(-4, 2)), Point::new(-1, 0));
+        assert_eq!(t.adjacent(&Point::new(1, 0)).len(), 4);
+        assert_eq!(
+            t.neighbours_with_values(&Point::new(1, 0), Neighbourhood::VonNeumann)
+                .iter()
+                .map(|(_, &v)| v)
+                .collect::<String>(),
+            "CDCE"
+        );
+    }
+
+    #[test]
+    fn test_tiled_distance() {
+        let g = abc_grid(Point::new(-1, -1));
+        let t = g.tiled();
+        // Everything is passable so the cells reached form a diamond
+        let d = t.distance_map_limit(&[Point::new(0, 0)], |_| true, 3);
+        assert_eq!(d.len(), 25);
+        assert_eq!((d.start, d.end), (Point::new(-3, -3), Point::new(3, 3)));
+        assert_eq!(d.get(&Point::new(-2, 1)), Some(&3));
+        // Only the Es are passable (one per tile)
+        let d = t.distance_map_limit(&[Point::new(0, 0)], |&c| c == 'E', 10);
+        assert_eq!(d.len(), 1);
+    }
+}
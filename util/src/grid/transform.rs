@@ -0,0 +1,205 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point};
+use std::fmt::Display;
+
+// Read-only view of a transformed grid - cells are mapped back to the
+// underlying grid on access rather than copied, so views can be chained
+// (eg. g.view().rotate_cw().flip_vertical()) and only copied with to_grid
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    pub start: Point,
+    pub end: Point,
+    pub size: Offset,
+    // Offset in grid = m * offset in view + t
+    m: [[i64; 2]; 2],
+    t: Offset,
+}
+
+borrowed_copy!(GridView);
+
+impl<'a, T> GridView<'a, T> {
+    pub fn check_bounds(&self, p: &Point) -> bool {
+        (p.x >= self.start.x) && (p.y >= self.start.y) && (p.x <= self.end.x) && (p.y <= self.end.y)
+    }
+    pub fn get(&self, p: &Point) -> Option<&'a T> {
+        if self.check_bounds(p) {
+            let o = *p - self.start;
+            let x = self.m[0][0] * o.dx + self.m[0][1] * o.dy + self.t.dx;
+            let y = self.m[1][0] * o.dx + self.m[1][1] * o.dy + self.t.dy;
+            self.grid.data.get((x + y * self.grid.size.dx) as usize)
+        } else {
+            None
+        }
+    }
+    // Compose with a transform mapping offsets in the new view (a * o + b)
+    // to offsets in this one
+    fn apply(&self, a: [[i64; 2]; 2], b: Offset, size: Offset) -> Self {
+        let m = &self.m;
+        GridView {
+            grid: self.grid,
+            start: self.start,
+            end: self.start + (size + Offset::new(-1, -1)),
+            size,
+            m: [
+                [
+                    m[0][0] * a[0][0] + m[0][1] * a[1][0],
+                    m[0][0] * a[0][1] + m[0][1] * a[1][1],
+                ],
+                [
+                    m[1][0] * a[0][0] + m[1][1] * a[1][0],
+                    m[1][0] * a[0][1] + m[1][1] * a[1][1],
+                ],
+            ],
+            t: Offset::new(
+                m[0][0] * b.dx + m[0][1] * b.dy + self.t.dx,
+                m[1][0] * b.dx + m[1][1] * b.dy + self.t.dy,
+            ),
+        }
+    }
+    pub fn transpose(&self) -> Self {
+        let Offset { dx: w, dy: h } = self.size;
+        self.apply([[0, 1], [1, 0]], Offset::new(0, 0), Offset::new(h, w))
+    }
+    pub fn rotate_cw(&self) -> Self {
+        let Offset { dx: w, dy: h } = self.size;
+        self.apply([[0, 1], [-1, 0]], Offset::new(0, h - 1), Offset::new(h, w))
+    }
+    pub fn rotate_ccw(&self) -> Self {
+        let Offset { dx: w, dy: h } = self.size;
+        self.apply([[0, -1], [1, 0]], Offset::new(w - 1, 0), Offset::new(h, w))
+    }
+    // Mirror left-right
+    pub fn flip_horizontal(&self) -> Self {
+        self.apply(
+            [[-1, 0], [0, 1]],
+            Offset::new(self.size.dx - 1, 0),
+            self.size,
+        )
+    }
+    // Mirror top-bottom
+    pub fn flip_vertical(&self) -> Self {
+        self.apply(
+            [[1, 0], [0, -1]],
+            Offset::new(0, self.size.dy - 1),
+            self.size,
+        )
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        let mut data = Vec::with_capacity((self.size.dx * self.size.dy) as usize);
+        for y in self.start.y..=self.end.y {
+            for x in self.start.x..=self.end.x {
+                data.push(self.get(&Point::new(x, y)).unwrap().clone());
+            }
+        }
+        Grid {
+            start: self.start,
+            end: self.end,
+            size: self.size,
+            data,
+        }
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.start.y..=self.end.y {
+            for x in self.start.x..=self.end.x {
+                write!(f, "{}", self.get(&Point::new(x, y)).unwrap())?
+            }
+            writeln!(f)?
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            start: self.start,
+            end: self.end,
+            size: self.size,
+            m: [[1, 0], [0, 1]],
+            t: Offset::new(0, 0),
+        }
+    }
+}
+
+// Transformed grids keep the original start point - the end moves to
+// reflect the new size (which only changes for transpose/rotate)
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+    pub fn rotate_cw(&self) -> Self {
+        self.view().rotate_cw().to_grid()
+    }
+    pub fn rotate_ccw(&self) -> Self {
+        self.view().rotate_ccw().to_grid()
+    }
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::abc_grid;
+
+    #[test]
+    fn test_transforms() {
+        let g = abc_grid(Point::new(-1, 5));
+        assert_eq!(g.transpose().to_string(), "AD\nBE\nCF\n");
+        assert_eq!(g.rotate_cw().to_string(), "DA\nEB\nFC\n");
+        assert_eq!(g.rotate_ccw().to_string(), "CF\nBE\nAD\n");
+        assert_eq!(g.flip_horizontal().to_string(), "CBA\nFED\n");
+        assert_eq!(g.flip_vertical().to_string(), "DEF\nABC\n");
+    }
+
+    #[test]
+    fn test_transform_bounds() {
+        let g = abc_grid(Point::new(-1, 5));
+        let t = g.rotate_cw();
+        assert_eq!(
+            (t.start, t.end, t.size),
+            (g.start, Point::new(0, 7), Offset::new(2, 3))
+        );
+        assert_eq!(t.get(&Point::new(-1, 5)), Some(&'D'));
+        assert_eq!(t.get(&Point::new(0, 7)), Some(&'C'));
+        assert_eq!(t.get(&Point::new(1, 7)), None);
+        let f = g.flip_vertical();
+        assert_eq!((f.start, f.end), (g.start, g.end));
+        assert_eq!(f.get(&Point::new(1, 5)), Some(&'F'));
+    }
+
+    #[test]
+    fn test_view() {
+        let g = abc_grid(Point::new(-1, 5));
+        let v = g.view();
+        assert_eq!(v.to_string(), g.to_string());
+        assert_eq!(v.rotate_cw().to_string(), g.rotate_cw().to_string());
+        // Chained views match copying transforms
+        assert_eq!(
+            v.rotate_cw().rotate_cw().to_string(),
+            g.flip_horizontal().flip_vertical().to_string()
+        );
+        assert_eq!(v.rotate_cw().rotate_ccw().to_string(), g.to_string());
+        assert_eq!(
+            v.transpose().flip_horizontal().to_string(),
+            g.rotate_cw().to_string()
+        );
+        let r = v.rotate_ccw();
+        assert_eq!(r.get(&Point::new(-1, 5)), Some(&'C'));
+        assert_eq!(r.get(&Point::new(0, 7)), Some(&'D'));
+        assert_eq!(r.get(&Point::new(-2, 5)), None);
+        let t = r.to_grid();
+        assert_eq!((t.start, t.end), (r.start, r.end));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point};
+use std::fmt::Display;
+
+// Borrowed rectangular part of a grid - points are in the grid's own
+// coordinates (so the window starts at its top left corner, not 0,0)
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    pub start: Point,
+    pub end: Point,
+    pub size: Offset,
+}
+
+borrowed_copy!(Window);
+
+impl<'a, T> Window<'a, T> {
+    pub fn check_bounds(&self, p: &Point) -> bool {
+        (p.x >= self.start.x) && (p.y >= self.start.y) && (p.x <= self.end.x) && (p.y <= self.end.y)
+    }
+    pub fn get(&self, p: &Point) -> Option<&'a T> {
+        if self.check_bounds(p) {
+            self.grid.get(p)
+        } else {
+            None
+        }
+    }
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        (self.start.y..=self.end.y).flat_map(move |y| {
+            (self.start.x..=self.end.x).map(move |x| {
+                let p = Point::new(x, y);
+                (p, self.grid.get(&p).unwrap())
+            })
+        })
+    }
+    // Number of cells which differ from pattern (laid over the window from
+    // its top left), giving up once more than max are found
+    pub fn mismatches(&self, pattern: &Grid<T>, max: usize) -> usize
+    where
+        T: PartialEq,
+    {
+        assert_eq!(self.size, pattern.size, "pattern size differs");
+        self.iter_points()
+            .zip(&pattern.data)
+            .filter(|((_, a), b)| a != b)
+            .take(max + 1)
+            .count()
+    }
+}
+
+impl<T: Clone> Window<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            start: self.start,
+            end: self.end,
+            size: self.size,
+            data: self.iter_points().map(|(_, v)| v.clone()).collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for Window<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (p, v) in self.iter_points() {
+            write!(f, "{}", v)?;
+            if p.x == self.end.x {
+                writeln!(f)?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    // None unless the window is entirely inside the grid
+    pub fn window(&self, top_left: &Point, size: Offset) -> Option<Window<'_, T>> {
+        let end = *top_left + (size + Offset::new(-1, -1));
+        let inside =
+            size.dx > 0 && size.dy > 0 && self.check_bounds(top_left) && self.check_bounds(&end);
+        inside.then_some(Window {
+            grid: self,
+            start: *top_left,
+            end,
+            size,
+        })
+    }
+    // Top left of every position where pattern matches exactly (in row
+    // order)
+    pub fn find_pattern(&self, pattern: &Grid<T>) -> Vec<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_pattern_approx(pattern, 0)
+    }
+    // As find_pattern allowing up to k cells to differ
+    pub fn find_pattern_approx(&self, pattern: &Grid<T>, k: usize) -> Vec<Point>
+    where
+        T: PartialEq,
+    {
+        let last = self.end + (pattern.size * -1 + Offset::new(1, 1));
+        let mut out = vec![];
+        for y in self.start.y..=last.y {
+            for x in self.start.x..=last.x {
+                let p = Point::new(x, y);
+                if let Some(w) = self.window(&p, pattern.size) {
+                    if w.mismatches(pattern, k) <= k {
+                        out.push(p);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // Copy of a window (keeping its coordinates)
+    pub fn crop(&self, top_left: &Point, size: Offset) -> Option<Grid<T>> {
+        self.window(top_left, size).map(|w| w.to_grid())
+    }
+    // Surround with a border n cells wide - the start moves out by n so the
+    // original cells keep their points
+    pub fn pad(&self, n: usize, value: T) -> Grid<T> {
+        let o = Offset::new(n as i64, n as i64);
+        let mut g = Grid::empty(&(self.start + o * -1), &(self.end + o), value);
+        for (p, v) in self.iter_points() {
+            g.set(&p, v.clone()).unwrap();
+        }
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::fixtures::char_grid;
+
+    #[test]
+    fn test_window() {
+        let g = char_grid("ABCD\nEFGH\nIJKL\n");
+        let w = g.window(&Point::new(1, 1), Offset::new(2, 2)).unwrap();
+        assert_eq!(w.to_string(), "FG\nJK\n");
+        assert_eq!(w.get(&Point::new(2, 2)), Some(&'K'));
+        assert_eq!(w.get(&Point::new(0, 0)), None);
+        assert!(g.window(&Point::new(3, 1), Offset::new(2, 2)).is_none());
+        assert!(g.window(&Point::new(0, 0), Offset::new(0, 2)).is_none());
+        let c = g.crop(&Point::new(2, 0), Offset::new(2, 3)).unwrap();
+        assert_eq!(c.to_string(), "CD\nGH\nKL\n");
+        assert_eq!((c.start, c.end), (Point::new(2, 0), Point::new(3, 2)));
+    }
+
+    #[test]
+    fn test_pad() {
+        let g = char_grid("AB\n");
+        let p = g.pad(1, '.');
+        assert_eq!(p.to_string(), "....\n.AB.\n....\n");
+        assert_eq!(p.start, Point::new(-1, -1));
+        assert_eq!(p.get(&Point::new(1, 0)), Some(&'B'));
+        assert_eq!(g.pad(0, '.').to_string(), g.to_string());
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let g = char_grid("#..#.\n.##..\n#..#.\n.##.#\n");
+        let pattern = char_grid("#.\n.#\n");
+        assert_eq!(
+            g.find_pattern(&pattern),
+            vec![
+                Point::new(0, 0),
+                Point::new(2, 1),
+                Point::new(0, 2),
+                Point::new(3, 2)
+            ]
+        );
+        assert_eq!(g.find_pattern_approx(&pattern, 1).len(), 5);
+        assert_eq!(g.find_pattern_approx(&pattern, 4).len(), 12);
+        assert!(g.find_pattern(&char_grid("######\n")).is_empty());
+    }
+}
@@ -1,5 +1,9 @@
+pub mod automaton;
 pub mod combinations;
 pub mod graph;
 pub mod grid;
+pub mod parse;
 pub mod point;
+pub mod runner;
 pub mod simplegraph;
+pub mod sparsegrid;
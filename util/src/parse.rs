@@ -0,0 +1,383 @@
+use crate::grid::Grid;
+use std::fmt::Display;
+use std::io::ErrorKind::InvalidData;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+// Parse failure with the (1-based) line/column and text it was found at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub msg: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.msg, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Lets parse_input functions returning io::Result use ? on parse errors
+impl From<ParseError> for std::io::Error {
+    fn from(e: ParseError) -> Self {
+        std::io::Error::new(InvalidData, e)
+    }
+}
+
+// Slice of an input line which remembers where it came from so that
+// errors can point at the offending text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str, line: usize) -> Self {
+        Span {
+            text,
+            line,
+            column: 1,
+        }
+    }
+    pub fn error(&self, msg: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            text: self.text.to_string(),
+            msg: msg.to_string(),
+        }
+    }
+    // Sub-span from a &str which must point into self.text
+    fn sub(&self, s: &'a str) -> Span<'a> {
+        let offset = s.as_ptr() as usize - self.text.as_ptr() as usize;
+        Span {
+            text: s,
+            line: self.line,
+            column: self.column + self.text[..offset].chars().count(),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    pub fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+    pub fn split_once(&self, delim: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(delim) {
+            Some((a, b)) => Ok((self.sub(a), self.sub(b))),
+            None => Err(self.error(&format!("expected {:?}", delim))),
+        }
+    }
+    pub fn split(&self, delims: &[char]) -> Vec<Span<'a>> {
+        self.text.split(delims).map(|s| self.sub(s)).collect()
+    }
+    pub fn split_whitespace(&self) -> Vec<Span<'a>> {
+        self.text.split_whitespace().map(|s| self.sub(s)).collect()
+    }
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(s) => Ok(self.sub(s)),
+            None => Err(self.error(&format!("expected {:?}", prefix))),
+        }
+    }
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(s) => Ok(self.sub(s)),
+            None => Err(self.error(&format!("expected {:?}", suffix))),
+        }
+    }
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse::<T>()
+            .map_err(|_| self.error(&format!("expected {}", std::any::type_name::<T>())))
+    }
+    // Whitespace and/or comma separated list of numbers
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.split(&[' ', ','])
+            .iter()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<T>())
+            .collect()
+    }
+    // Map each char through f (None is an error)
+    pub fn cells<T>(&self, f: impl Fn(char) -> Option<T>) -> Result<Vec<T>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                f(c).ok_or_else(|| {
+                    self.sub(&self.text[i..i + c.len_utf8()])
+                        .error("invalid cell")
+                })
+            })
+            .collect()
+    }
+    // Delimited list with each item trimmed ("a, b,c" -> [a, b, c])
+    pub fn list(&self, delim: char) -> Vec<Span<'a>> {
+        self.text.split(delim).map(|s| self.sub(s).trim()).collect()
+    }
+    // Named block - "px{a<2006:qkq,rfg}" -> ("px", "a<2006:qkq,rfg")
+    pub fn block(&self, open: &str, close: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (name, rest) = self.split_once(open)?;
+        Ok((name, rest.strip_suffix(close)?))
+    }
+    // Exactly n fields split on delims
+    pub fn fields<const N: usize>(&self, delims: &[char]) -> Result<[Span<'a>; N], ParseError> {
+        self.split(delims)
+            .try_into()
+            .map_err(|v: Vec<_>| self.error(&format!("expected {} fields (got {})", N, v.len())))
+    }
+}
+
+// All integers embedded in s ("x=-1, y=2" -> [-1, 2]) - a '-' is only
+// taken as a sign when it doesn't follow a letter or digit
+pub fn extract_numbers<T: FromStr>(s: &str) -> Vec<T> {
+    let b = s.as_bytes();
+    let mut out = vec![];
+    let mut i = 0;
+    while i < b.len() {
+        let sign = b[i] == b'-'
+            && b.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+            && (i == 0 || !b[i - 1].is_ascii_alphanumeric());
+        if sign || b[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < b.len() && b[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(n) = s[start..i].parse::<T>() {
+                out.push(n);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+fn read_lines(input: &mut impl Read) -> std::io::Result<Vec<String>> {
+    BufReader::new(input).lines().collect()
+}
+
+fn spans(lines: &[String]) -> Vec<Span<'_>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| Span::new(l, i + 1))
+        .collect()
+}
+
+// Apply f to each line of input (stopping at the first error)
+pub fn lines<T>(
+    input: &mut impl Read,
+    mut f: impl FnMut(Span) -> Result<T, ParseError>,
+) -> std::io::Result<Vec<T>> {
+    let mut out = vec![];
+    for (i, l) in BufReader::new(input).lines().enumerate() {
+        out.push(f(Span::new(&l?, i + 1))?);
+    }
+    Ok(out)
+}
+
+// Apply f to each block of lines separated by one or more blank lines
+pub fn sections<T>(
+    input: &mut impl Read,
+    mut f: impl FnMut(&[Span]) -> Result<T, ParseError>,
+) -> std::io::Result<Vec<T>> {
+    let lines = read_lines(input)?;
+    let mut out = vec![];
+    for section in spans(&lines).split(|l| l.text.trim().is_empty()) {
+        if !section.is_empty() {
+            out.push(f(section)?);
+        }
+    }
+    Ok(out)
+}
+
+// "key<sep>a b c" lines as (key, values) in input order - values can be
+// separated by whitespace and/or commas
+pub fn key_lists(input: &mut impl Read, sep: &str) -> std::io::Result<Vec<(String, Vec<String>)>> {
+    lines(input, |l| {
+        let (k, v) = l.split_once(sep)?;
+        let values = v
+            .split(&[' ', ','])
+            .iter()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.text.to_string())
+            .collect::<Vec<_>>();
+        Ok((k.trim().text.to_string(), values))
+    })
+}
+
+// Map a block of lines (e.g. a section) to a rectangular grid with each
+// char mapped through f (None is an error) - see Grid::from_lines
+pub fn grid_lines<T>(lines: &[Span], f: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    Grid::from_lines(lines, |b| f(b as char).ok_or("invalid cell"))
+}
+
+// Read a rectangular grid of chars mapping each through f (None is an error)
+// - see Grid::from_reader
+pub fn grid<T>(input: &mut impl Read, f: impl Fn(char) -> Option<T>) -> std::io::Result<Grid<T>> {
+    Grid::from_reader(input, |b| f(b as char).ok_or("invalid cell"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    #[test]
+    fn test_span() {
+        let s = Span::new("abc: 1 -2  3", 4);
+        let (k, v) = s.split_once(": ").unwrap();
+        assert_eq!((k.text, k.column), ("abc", 1));
+        assert_eq!((v.text, v.column), ("1 -2  3", 6));
+        assert_eq!(v.numbers::<i32>(), Ok(vec![1, -2, 3]));
+        let n = v.split_whitespace();
+        assert_eq!(
+            n.iter().map(|s| s.column).collect::<Vec<_>>(),
+            vec![6, 8, 12]
+        );
+        assert_eq!(s.strip_prefix("ab").unwrap().text, "c: 1 -2  3");
+        assert_eq!(Span::new("  x ", 1).trim().column, 3);
+        let [a, b, c] = Span::new("1,2~3", 1).fields::<3>(&[',', '~']).unwrap();
+        assert_eq!((a.text, b.text, c.column), ("1", "2", 5));
+    }
+
+    #[test]
+    fn test_span_errors() {
+        let s = Span::new("1 2 x 4", 7);
+        let e = s.numbers::<u32>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (7, 5, "x"));
+        assert_eq!(
+            e.to_string(),
+            "line 7, column 5: expected u32 (found \"x\")"
+        );
+        let e = s.split_once(":").unwrap_err();
+        assert_eq!((e.column, e.msg.as_str()), (1, "expected \":\""));
+        assert!(s.strip_suffix("5").is_err());
+        assert!(s.fields::<3>(&[' ']).is_err());
+    }
+
+    #[test]
+    fn test_lines() {
+        let v = lines(&mut "1,2\n3,4\n".as_bytes(), |l| l.numbers::<u8>()).unwrap();
+        assert_eq!(v, vec![vec![1, 2], vec![3, 4]]);
+        let e = lines(&mut "1,2\n3,a\n".as_bytes(), |l| l.numbers::<u8>()).unwrap_err();
+        assert_eq!(e.kind(), InvalidData);
+        assert_eq!(e.to_string(), "line 2, column 3: expected u8 (found \"a\")");
+    }
+
+    #[test]
+    fn test_list_block() {
+        let s = Span::new("px{a<2006:qkq, m>2090:A,rfg}", 1);
+        let (name, inner) = s.block("{", "}").unwrap();
+        assert_eq!(
+            (name.text, inner.text, inner.column),
+            ("px", "a<2006:qkq, m>2090:A,rfg", 4)
+        );
+        let l = inner.list(',');
+        assert_eq!(
+            l.iter().map(|s| s.text).collect::<Vec<_>>(),
+            vec!["a<2006:qkq", "m>2090:A", "rfg"]
+        );
+        assert_eq!(l[1].column, 16);
+        assert!(s.block("[", "]").is_err());
+        assert!(Span::new("px{a", 1).block("{", "}").is_err());
+    }
+
+    #[test]
+    fn test_extract_numbers() {
+        assert_eq!(extract_numbers::<i64>("x=-1, y=2 @ -30"), vec![-1, 2, -30]);
+        assert_eq!(
+            extract_numbers::<i64>("seed-to-soil 3-4 a-5"),
+            vec![3, 4, 5]
+        );
+        assert_eq!(
+            extract_numbers::<u32>("Card  12: 41 48 | 83"),
+            vec![12, 41, 48, 83]
+        );
+        assert_eq!(extract_numbers::<i32>("no numbers - here"), vec![]);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\n\nc\n\nd\ne\n";
+        let s = sections(&mut input.as_bytes(), |s| Ok(s.len())).unwrap();
+        assert_eq!(s, vec![2, 1, 2]);
+        let lines = sections(&mut input.as_bytes(), |s| Ok(s[0].line)).unwrap();
+        assert_eq!(lines, vec![1, 5, 7]);
+        let e = sections(&mut input.as_bytes(), |s| s[0].parse::<u8>()).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 1: expected u8 (found \"a\")");
+    }
+
+    #[test]
+    fn test_key_lists() {
+        let k = key_lists(&mut "jqt: rhn xhk\nbroadcaster: a, b,c\n".as_bytes(), ":").unwrap();
+        assert_eq!(
+            k,
+            vec![
+                (
+                    "jqt".to_string(),
+                    vec!["rhn".to_string(), "xhk".to_string()]
+                ),
+                (
+                    "broadcaster".to_string(),
+                    vec!["a".to_string(), "b".to_string(), "c".to_string()]
+                ),
+            ]
+        );
+        assert!(key_lists(&mut "jqt rhn\n".as_bytes(), ":").is_err());
+    }
+
+    #[test]
+    fn test_grid_lines() {
+        let wall = |c: char| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let grids = sections(&mut "#.\n.#\n\n##.\n".as_bytes(), |s| grid_lines(s, wall)).unwrap();
+        assert_eq!(
+            grids[0].find(&true),
+            vec![Point::new(0, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grids[1].end, Point::new(2, 0));
+        let e = sections(&mut "#.\n.#\n\n##.\n#\n".as_bytes(), |s| {
+            grid_lines(s, wall)
+        })
+        .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 5, column 1: expected 3 columns (found \"#\")"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |c: char| c.to_digit(10);
+        let g = grid(&mut "123\n456\n".as_bytes(), digit).unwrap();
+        assert_eq!(g.get(&Point::new(2, 1)), Some(&6));
+        assert_eq!(g.end, Point::new(2, 1));
+        let e = grid(&mut "123\n4x6\n".as_bytes(), digit).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: invalid cell (found \"x\")"
+        );
+        let e = grid(&mut "123\n45\n".as_bytes(), digit).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: expected 3 columns (found \"45\")"
+        );
+        assert!(grid(&mut "".as_bytes(), digit).is_err());
+    }
+}
@@ -14,6 +14,9 @@ impl Point {
     pub fn adjacent(&self) -> impl Iterator<Item = Point> + '_ {
         ADJACENT.into_iter().map(|offset| self.add(offset))
     }
+    pub fn adjacent8(&self) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT8.into_iter().map(|offset| self.add(offset))
+    }
 }
 
 impl Point {
@@ -89,7 +92,67 @@ pub const DOWN: Offset = Offset { dx: 0, dy: 1 };
 pub const RIGHT: Offset = Offset { dx: 1, dy: 0 };
 pub const LEFT: Offset = Offset { dx: -1, dy: 0 };
 
+pub const UP_RIGHT: Offset = Offset { dx: 1, dy: -1 };
+pub const DOWN_RIGHT: Offset = Offset { dx: 1, dy: 1 };
+pub const DOWN_LEFT: Offset = Offset { dx: -1, dy: 1 };
+pub const UP_LEFT: Offset = Offset { dx: -1, dy: -1 };
+
 pub const ADJACENT: [Offset; 4] = [UP, RIGHT, DOWN, LEFT];
+pub const DIAGONALS: [Offset; 4] = [UP_RIGHT, DOWN_RIGHT, DOWN_LEFT, UP_LEFT];
+// Clockwise from UP
+pub const ADJACENT8: [Offset; 8] = [
+    UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT,
+];
+
+// Neighbourhoods can be passed anywhere a list of offsets is expected
+// (eg. Grid::offset)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    // Orthogonal neighbours (ADJACENT)
+    VonNeumann,
+    // Orthogonal and diagonal neighbours (ADJACENT8)
+    Moore,
+    // Every offset within manhattan distance r (|dx| + |dy| <= r, a diamond)
+    // excluding the centre, in row order
+    Manhattan(u32),
+    // Every offset within chebyshev distance r (max(|dx|, |dy|) <= r, a
+    // square) excluding the centre, in row order
+    Chebyshev(u32),
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> Vec<Offset> {
+        match self {
+            Neighbourhood::VonNeumann => ADJACENT.to_vec(),
+            Neighbourhood::Moore => ADJACENT8.to_vec(),
+            Neighbourhood::Manhattan(r) => {
+                let r = *r as i64;
+                (-r..=r)
+                    .flat_map(|dy| {
+                        let w = r - dy.abs();
+                        (-w..=w).map(move |dx| Offset::new(dx, dy))
+                    })
+                    .filter(|&o| o != Offset::new(0, 0))
+                    .collect()
+            }
+            Neighbourhood::Chebyshev(r) => {
+                let r = *r as i64;
+                (-r..=r)
+                    .flat_map(|dy| (-r..=r).map(move |dx| Offset::new(dx, dy)))
+                    .filter(|&o| o != Offset::new(0, 0))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl IntoIterator for Neighbourhood {
+    type Item = Offset;
+    type IntoIter = std::vec::IntoIter<Offset>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.offsets().into_iter()
+    }
+}
 
 impl Offset {
     pub fn new(dx: i64, dy: i64) -> Offset {
@@ -157,6 +220,44 @@ mod tests {
         assert_eq!(Point::try_from("-3,6"), Ok(Point::new(-3, 6)));
     }
     #[test]
+    fn test_adjacent8() {
+        let p = Point::new(5, 5);
+        let adjacent = p.adjacent8().collect::<Vec<_>>();
+        assert_eq!(adjacent.len(), 8);
+        assert_eq!(adjacent[0], Point::new(5, 4));
+        assert_eq!(adjacent[1], Point::new(6, 4));
+        assert!(adjacent
+            .iter()
+            .all(|a| a.x_offset(&p) <= 1 && a.y_offset(&p) <= 1 && *a != p));
+        assert!(p.adjacent().all(|a| adjacent.contains(&a)));
+        assert!(DIAGONALS.iter().all(|&d| adjacent.contains(&(p + d))));
+    }
+    #[test]
+    fn test_neighbourhood() {
+        assert_eq!(Neighbourhood::VonNeumann.offsets(), ADJACENT.to_vec());
+        assert_eq!(Neighbourhood::Moore.into_iter().count(), 8);
+        let mut r1 = Neighbourhood::Manhattan(1).offsets();
+        let mut adjacent = ADJACENT.to_vec();
+        r1.sort();
+        adjacent.sort();
+        assert_eq!(r1, adjacent);
+        let r2 = Neighbourhood::Manhattan(2).offsets();
+        assert_eq!(r2.len(), 12);
+        assert_eq!(r2[0], Offset::new(0, -2));
+        assert!(r2
+            .iter()
+            .all(|&o| Point::new(0, 0).manhattan(&(Point::new(0, 0) + o)) <= 2));
+        let mut c1 = Neighbourhood::Chebyshev(1).offsets();
+        let mut adjacent8 = ADJACENT8.to_vec();
+        c1.sort();
+        adjacent8.sort();
+        assert_eq!(c1, adjacent8);
+        let c2 = Neighbourhood::Chebyshev(2).offsets();
+        assert_eq!(c2.len(), 24);
+        assert_eq!(c2[0], Offset::new(-2, -2));
+        assert!(c2.iter().all(|o| o.dx.abs() <= 2 && o.dy.abs() <= 2));
+    }
+    #[test]
     fn test_adjacent() {
         assert_eq!(
             Point::new(5, 5).adjacent().collect::<Vec<_>>(),
@@ -0,0 +1,186 @@
+use std::fmt::Debug;
+use std::fs::File;
+use std::hint::black_box;
+use std::io::ErrorKind::{InvalidInput, Unsupported};
+use std::io::{Error, Read};
+use std::time::{Duration, Instant};
+
+// Each day implements Solution and hands it to the driver which deals with
+// loading the input, timing each part and printing the results
+pub trait Solution {
+    type Input;
+    type Output: Debug;
+
+    fn parse(input: &mut impl Read) -> std::io::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output;
+    // None if there is no part 2 (day 25 only has a single part)
+    fn part2(_input: &Self::Input) -> Option<Self::Output> {
+        None
+    }
+}
+
+// Run a single part (or both if part is None) - takes a dyn Read so that
+// run_part::<S> can be used as a plain fn pointer when dispatching on day.
+// Asking for part 2 of a day without one is an Unsupported error
+pub fn run_part<S: Solution>(mut input: &mut dyn Read, part: Option<u32>) -> std::io::Result<()> {
+    let (part1, part2) = match part {
+        None => (true, true),
+        Some(1) => (true, false),
+        Some(2) => (false, true),
+        Some(p) => {
+            return Err(Error::new(InvalidInput, format!("Invalid part: {}", p)));
+        }
+    };
+    let input = S::parse(&mut input)?;
+    if part1 {
+        let p1 = Instant::now();
+        println!(
+            "Part1: {:?} ({}s)",
+            S::part1(&input),
+            p1.elapsed().as_secs_f32()
+        );
+    }
+    if part2 {
+        let p2 = Instant::now();
+        match S::part2(&input) {
+            Some(result) => println!("Part2: {:?} ({}s)", result, p2.elapsed().as_secs_f32()),
+            None if part == Some(2) => return Err(Error::new(Unsupported, "No part 2")),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+// Parse and solve all parts returning the results formatted as strings
+// (used to check answers against a manifest)
+pub fn answers<S: Solution>(mut input: &mut dyn Read) -> std::io::Result<Vec<String>> {
+    let input = S::parse(&mut input)?;
+    let mut out = vec![format!("{:?}", S::part1(&input))];
+    if let Some(result) = S::part2(&input) {
+        out.push(format!("{:?}", result));
+    }
+    Ok(out)
+}
+
+// Time parse, part1 and part2 separately - each stage is run `warmup` times
+// untimed followed by `iterations` timed runs
+pub fn bench<S: Solution>(
+    input: &mut dyn Read,
+    warmup: usize,
+    iterations: usize,
+) -> std::io::Result<Vec<(&'static str, Vec<Duration>)>> {
+    fn time<T>(warmup: usize, iterations: usize, f: impl Fn() -> T) -> Vec<Duration> {
+        for _ in 0..warmup {
+            black_box(f());
+        }
+        (0..iterations)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed()
+            })
+            .collect()
+    }
+    let mut data = Vec::new();
+    input.read_to_end(&mut data)?;
+    let parsed = S::parse(&mut data.as_slice())?;
+    let mut out = vec![
+        (
+            "parse",
+            time(warmup, iterations, || S::parse(&mut data.as_slice())),
+        ),
+        ("part1", time(warmup, iterations, || S::part1(&parsed))),
+    ];
+    // The untimed call doubles as a warmup
+    if S::part2(&parsed).is_some() {
+        out.push(("part2", time(warmup, iterations, || S::part2(&parsed))));
+    }
+    Ok(out)
+}
+
+pub fn run<S: Solution>(input: &mut dyn Read) -> std::io::Result<()> {
+    run_part::<S>(input, None)
+}
+
+pub fn main<S: Solution>() -> std::io::Result<()> {
+    let mut f = File::open("input.txt")?;
+    run::<S>(&mut f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Output = u32;
+        fn parse(input: &mut impl Read) -> std::io::Result<Self::Input> {
+            let mut s = String::new();
+            input.read_to_string(&mut s)?;
+            Ok(s.split_whitespace()
+                .filter_map(|w| w.parse().ok())
+                .collect())
+        }
+        fn part1(input: &Self::Input) -> Self::Output {
+            input.iter().sum()
+        }
+        fn part2(input: &Self::Input) -> Option<Self::Output> {
+            Some(input.iter().product())
+        }
+    }
+
+    // Part 1 only
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type Output = usize;
+        fn parse(input: &mut impl Read) -> std::io::Result<Self::Input> {
+            let mut s = String::new();
+            input.read_to_string(&mut s)?;
+            Ok(s.split_whitespace().count())
+        }
+        fn part1(input: &Self::Input) -> Self::Output {
+            *input
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Sum::parse(&mut "1 2 3 4".as_bytes()).unwrap();
+        assert_eq!(Sum::part1(&input), 10);
+        assert_eq!(Sum::part2(&input), Some(24));
+        assert!(run::<Sum>(&mut "1 2 3 4".as_bytes()).is_ok());
+        assert!(run_part::<Sum>(&mut "1 2 3 4".as_bytes(), Some(2)).is_ok());
+        assert!(run_part::<Sum>(&mut "1 2 3 4".as_bytes(), Some(3)).is_err());
+        assert_eq!(
+            answers::<Sum>(&mut "1 2 3 4".as_bytes()).unwrap(),
+            vec!["10", "24"]
+        );
+        let timings = bench::<Sum>(&mut "1 2 3 4".as_bytes(), 1, 5).unwrap();
+        assert_eq!(
+            timings
+                .iter()
+                .map(|(s, t)| (*s, t.len()))
+                .collect::<Vec<_>>(),
+            vec![("parse", 5), ("part1", 5), ("part2", 5)]
+        );
+    }
+
+    #[test]
+    fn test_part1_only() {
+        assert_eq!(Count::part2(&3), None);
+        assert!(run::<Count>(&mut "a b c".as_bytes()).is_ok());
+        assert!(run_part::<Count>(&mut "a b c".as_bytes(), Some(1)).is_ok());
+        let e = run_part::<Count>(&mut "a b c".as_bytes(), Some(2)).unwrap_err();
+        assert_eq!(e.kind(), Unsupported);
+        assert_eq!(
+            answers::<Count>(&mut "a b c".as_bytes()).unwrap(),
+            vec!["3"]
+        );
+        let timings = bench::<Count>(&mut "a b c".as_bytes(), 0, 2).unwrap();
+        assert_eq!(timings.len(), 2);
+    }
+}
@@ -35,6 +35,15 @@ pub struct Graph<V>(HashMap<V, Vec<Edge<V>>>)
 where
     V: Clone + Eq + Hash;
 
+impl<V> Default for Graph<V>
+where
+    V: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<V> Graph<V>
 where
     V: Clone + Eq + Hash,
@@ -50,11 +59,11 @@ where
     }
     pub fn add_edge(&mut self, from: &V, to: &V, cost: u32) {
         // Create 'to' if necessary
-        self.0.entry(to.clone()).or_insert_with(|| vec![]);
+        self.0.entry(to.clone()).or_default();
         // Add edge
         self.0
             .entry(from.clone())
-            .or_insert_with(|| vec![])
+            .or_default()
             .push(Edge(to.clone(), cost));
     }
     pub fn vertices(&self) -> impl Iterator<Item = &V> {
@@ -85,7 +94,7 @@ where
         let mut open: BinaryHeap<Edge<V>> = BinaryHeap::new();
         let mut from: HashMap<V, V> = HashMap::new();
         let mut score: HashMap<V, u32> = HashMap::new();
-        open.push(Edge(start.clone(), h(&start)));
+        open.push(Edge(start.clone(), h(start)));
         score.insert(start.clone(), 0);
         while let Some(current) = open.pop() {
             if current.0 == *target {
@@ -94,15 +103,15 @@ where
             if let Some(v) = self.0.get(&current.0) {
                 for Edge(next, cost) in v.iter() {
                     let tentative = score[&current.0] + cost;
-                    if tentative < *score.get(&next).unwrap_or(&u32::MAX) {
+                    if tentative < *score.get(next).unwrap_or(&u32::MAX) {
                         from.insert(next.clone(), current.0.clone());
                         score.insert(next.clone(), tentative);
-                        open.push(Edge(next.clone(), tentative + h(&next)));
+                        open.push(Edge(next.clone(), tentative + h(next)));
                     }
                 }
             }
         }
-        if let Some(cost) = score.get(&target) {
+        if let Some(cost) = score.get(target) {
             let mut current = target.clone();
             let mut path = vec![current.clone()];
             while let Some(prev) = from.get(&current) {
@@ -110,9 +119,9 @@ where
                 current = prev.clone();
             }
             path.reverse();
-            return Some((cost.clone(), path));
+            Some((*cost, path))
         } else {
-            return None;
+            None
         }
     }
     pub fn astar_all<F>(&self, start: &V, h: F) -> (HashMap<V, u32>, HashMap<V, V>)
@@ -122,16 +131,16 @@ where
         let mut open: BinaryHeap<Edge<V>> = BinaryHeap::new();
         let mut from: HashMap<V, V> = HashMap::new();
         let mut score: HashMap<V, u32> = HashMap::new();
-        open.push(Edge(start.clone(), h(&start)));
+        open.push(Edge(start.clone(), h(start)));
         score.insert(start.clone(), 0);
         while let Some(current) = open.pop() {
             if let Some(v) = self.0.get(&current.0) {
                 for Edge(next, cost) in v.iter() {
                     let tentative = score[&current.0] + cost;
-                    if tentative < *score.get(&next).unwrap_or(&u32::MAX) {
+                    if tentative < *score.get(next).unwrap_or(&u32::MAX) {
                         from.insert(next.clone(), current.0.clone());
                         score.insert(next.clone(), tentative);
-                        open.push(Edge(next.clone(), tentative + h(&next)));
+                        open.push(Edge(next.clone(), tentative + h(next)));
                     }
                 }
             }
@@ -143,15 +152,9 @@ where
         for u in self.0.iter() {
             for v in self.0.iter() {
                 if u.0 == v.0 {
-                    cost.insert((&u.0, &v.0), Some(0));
+                    cost.insert((u.0, v.0), Some(0));
                 } else {
-                    cost.insert(
-                        (&u.0, &v.0),
-                        match u.1.iter().find(|&e| &e.0 == v.0) {
-                            Some(e) => Some(e.1),
-                            None => None,
-                        },
-                    );
+                    cost.insert((u.0, v.0), u.1.iter().find(|&e| &e.0 == v.0).map(|e| e.1));
                 }
             }
         }
@@ -161,10 +164,10 @@ where
                     let (c1, c2, c3) = (cost[&(u.0, v.0)], cost[&(u.0, r.0)], cost[&(r.0, v.0)]);
                     if let (None, Some(c2), Some(c3)) = (c1, c2, c3) {
                         // No existing route from u-v but we do have u->r->v
-                        cost.insert((&u.0, &v.0), Some(c2 + c3));
+                        cost.insert((u.0, v.0), Some(c2 + c3));
                     } else if let (Some(c1), Some(c2), Some(c3)) = (c1, c2, c3) {
                         if c2 + c3 < c1 {
-                            cost.insert((&u.0, &v.0), Some(c2 + c3));
+                            cost.insert((u.0, v.0), Some(c2 + c3));
                         }
                     }
                 }
@@ -357,11 +360,11 @@ mod tests {
         g
     }
 
-    fn adj(a: &Vec<Vec<u32>>, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    fn adj(a: &[Vec<u32>], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
         let (x_max, y_max) = (a[0].len() as i32, a.len() as i32);
         let (x, y) = (x as i32, y as i32);
         let mut out = vec![];
-        for (dx, dy) in vec![(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
             if x + dx >= 0 && x + dx < x_max && y + dy >= 0 && y + dy < y_max {
                 out.push(((x + dx) as usize, (y + dy) as usize));
             }
@@ -411,13 +414,7 @@ mod tests {
         let costs = g.floyd();
         for u in g.0.keys() {
             for v in g.0.keys() {
-                assert_eq!(
-                    costs[&(u, v)],
-                    match g.astar(u, v, |_| 1) {
-                        Some((c, _)) => Some(c),
-                        None => None,
-                    }
-                );
+                assert_eq!(costs[&(u, v)], g.astar(u, v, |_| 1).map(|(c, _)| c));
             }
         }
     }
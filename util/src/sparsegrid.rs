@@ -0,0 +1,232 @@
+use crate::grid::Grid;
+use crate::point::{Offset, Point, ADJACENT, ADJACENT8};
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// Unbounded grid which only stores the cells that have been set - start/end
+// track the bounding box of the cells set so far (they grow as needed but
+// aren't shrunk by remove). An empty grid has end < start
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    pub start: Point,
+    pub end: Point,
+    pub data: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            start: Point::new(0, 0),
+            end: Point::new(-1, -1),
+            data: HashMap::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn size(&self) -> Offset {
+        (self.end - self.start) + Offset::new(1, 1)
+    }
+    // Within the bounding box (the cell itself may not be set)
+    pub fn check_bounds(&self, p: &Point) -> bool {
+        (p.x >= self.start.x) && (p.y >= self.start.y) && (p.x <= self.end.x) && (p.y <= self.end.y)
+    }
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        self.data.get(p)
+    }
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.data.get_mut(p)
+    }
+    // Never fails as the grid grows to fit p (the Result matches Grid::set)
+    #[allow(clippy::result_unit_err)]
+    pub fn set(&mut self, p: &Point, new: T) -> Result<(), ()> {
+        if self.data.is_empty() {
+            (self.start, self.end) = (*p, *p);
+        } else {
+            self.start = Point::new(min(self.start.x, p.x), min(self.start.y, p.y));
+            self.end = Point::new(max(self.end.x, p.x), max(self.end.y, p.y));
+        }
+        self.data.insert(*p, new);
+        Ok(())
+    }
+    pub fn remove(&mut self, p: &Point) -> Option<T> {
+        self.data.remove(p)
+    }
+    // Neighbours aren't bounds checked as the grid is unbounded
+    pub fn adjacent(&self, p: &Point) -> Vec<Point> {
+        self.offset(p, ADJACENT)
+    }
+    pub fn adjacent8(&self, p: &Point) -> Vec<Point> {
+        self.offset(p, ADJACENT8)
+    }
+    pub fn offset(&self, p: &Point, offsets: impl IntoIterator<Item = Offset>) -> Vec<Point> {
+        offsets.into_iter().map(|o| *p + o).collect()
+    }
+    pub fn neighbours_with_values(
+        &self,
+        p: &Point,
+        offsets: impl IntoIterator<Item = Offset>,
+    ) -> Vec<(Point, &T)> {
+        offsets
+            .into_iter()
+            .filter_map(|o| self.get(&(*p + o)).map(|v| (*p + o, v)))
+            .collect()
+    }
+    // Set cells in row order
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        let mut points = self.data.iter().map(|(p, v)| (*p, v)).collect::<Vec<_>>();
+        points.sort_by_key(|(p, _)| (p.y, p.x));
+        points.into_iter()
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: PartialEq + Eq,
+{
+    // Matching points in row order (as for Grid::find)
+    pub fn find(&self, t: &T) -> Vec<Point> {
+        self.iter_points()
+            .filter_map(|(p, v)| if v == t { Some(p) } else { None })
+            .collect()
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    // Every cell of the grid which isn't background
+    pub fn from_grid(grid: &Grid<T>, background: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        grid.iter_points()
+            .filter(|(_, v)| *v != background)
+            .map(|(p, v)| (p, v.clone()))
+            .collect()
+    }
+    // Dense copy of the bounding box with unset cells as background
+    pub fn to_grid(&self, background: T) -> Grid<T> {
+        let mut g = Grid::empty(&self.start, &self.end, background);
+        for (p, v) in &self.data {
+            g.set(p, v.clone()).unwrap();
+        }
+        g
+    }
+    #[allow(clippy::result_unit_err)]
+    pub fn draw_line(&mut self, p1: &Point, p2: &Point, v: T) -> Result<(), ()> {
+        match (p1.x == p2.x, p1.y == p2.y) {
+            (true, _) => {
+                for y in min(p1.y, p2.y)..=max(p1.y, p2.y) {
+                    self.set(&Point::new(p1.x, y), v.clone())?;
+                }
+                Ok(())
+            }
+            (_, true) => {
+                for x in min(p1.x, p2.x)..=max(p1.x, p2.x) {
+                    self.set(&Point::new(x, p1.y), v.clone())?;
+                }
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut g = SparseGrid::new();
+        for (p, v) in iter {
+            g.set(&p, v).unwrap();
+        }
+        g
+    }
+}
+
+// Unset cells in the bounding box are shown as '.'
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.start.y..=self.end.y {
+            for x in self.start.x..=self.end.x {
+                match self.get(&Point::new(x, y)) {
+                    Some(v) => write!(f, "{}", v)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut g = SparseGrid::new();
+        assert!(g.is_empty());
+        assert_eq!(g.to_string(), "");
+        assert_eq!(g.set(&Point::new(-2, 3), 'A'), Ok(()));
+        assert_eq!((g.start, g.end), (Point::new(-2, 3), Point::new(-2, 3)));
+        g.set(&Point::new(1, -1), 'B').unwrap();
+        g.set(&Point::new(-2, 3), 'C').unwrap();
+        assert_eq!(g.get(&Point::new(-2, 3)), Some(&'C'));
+        assert_eq!((g.start, g.end), (Point::new(-2, -1), Point::new(1, 3)));
+        assert_eq!(g.size(), Offset::new(4, 5));
+        assert_eq!(g.get(&Point::new(1, -1)), Some(&'B'));
+        assert_eq!(g.get(&Point::new(0, 0)), None);
+        assert!(g.check_bounds(&Point::new(0, 0)));
+        assert_eq!(g.to_string(), "...B\n....\n....\n....\nC...\n");
+        assert_eq!(g.remove(&Point::new(1, -1)), Some('B'));
+        assert_eq!(g.len(), 1);
+        assert_eq!(g.end, Point::new(1, 3));
+    }
+
+    #[test]
+    fn test_sparse_grid_find() {
+        let g = [(3, 0), (0, 1), (1, 0), (2, 1)]
+            .into_iter()
+            .map(|(x, y)| (Point::new(x, y), if x % 2 == 0 { 'x' } else { 'o' }))
+            .collect::<SparseGrid<_>>();
+        assert_eq!(g.find(&'o'), vec![Point::new(1, 0), Point::new(3, 0)]);
+        assert_eq!(g.find(&'x'), vec![Point::new(0, 1), Point::new(2, 1)]);
+        assert_eq!(g.adjacent(&Point::new(-10, 0)).len(), 4);
+        assert_eq!(
+            g.neighbours_with_values(&Point::new(1, 1), ADJACENT),
+            vec![
+                (Point::new(1, 0), &'o'),
+                (Point::new(2, 1), &'x'),
+                (Point::new(0, 1), &'x')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid_convert() {
+        let g = Grid::parse_str("..#\n#..\n", |b| Ok::<_, &str>(b as char)).unwrap();
+        let s = SparseGrid::from_grid(&g, &'.');
+        assert_eq!(s.len(), 2);
+        assert_eq!((s.start, s.end), (g.start, g.end));
+        assert_eq!(s.to_string(), g.to_string());
+        assert_eq!(s.to_grid('.').to_string(), g.to_string());
+        let mut s = SparseGrid::new();
+        s.draw_line(&Point::new(5, 5), &Point::new(5, 7), '#')
+            .unwrap();
+        assert!(s
+            .draw_line(&Point::new(5, 5), &Point::new(6, 7), '#')
+            .is_err());
+        let d = s.to_grid(' ');
+        assert_eq!((d.start, d.end), (Point::new(5, 5), Point::new(5, 7)));
+        assert_eq!(d.to_string(), "#\n#\n#\n");
+    }
+}